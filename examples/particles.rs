@@ -1,5 +1,3 @@
-use std::f64::consts::PI;
-
 use macroquad::{
     color,
     prelude::*,
@@ -7,7 +5,11 @@ use macroquad::{
 };
 
 use jufi::{algebra::Vec2, utils::print};
-use jufi::physics::shapes::{Line, Particle};
+use jufi::physics::{
+    emitter::{Emitter, EmitterShape},
+    shapes::{Line, Wall},
+    sph::Fluid,
+};
 
 #[macroquad::main("Hello, World!")]
 async fn main() {
@@ -20,11 +22,12 @@ async fn main() {
     // Fonte principal
     let nunito = load_ttf_font("NunitoSans-Regular.ttf").await.unwrap();
 
-    // Vetor de partículas
-    let mut particles = vec![Particle::new(
-        Vec2::new(400.0, 300.0),
-        Vec2::new(200.0, 0.0).rotated(randf_range(-PI, PI)),
-    )];
+    // Emissor de partículas no centro da tela (começa com uma partícula)
+    let mut emitter = Emitter::new(Vec2::new(400.0, 300.0), EmitterShape::Point, 200.0);
+    emitter.burst(1);
+
+    // Fluido (água) que pode ser derramado dentro das linhas
+    let mut fluid = Fluid::new(Vec2::new(0.0, 500.0));
 
     // Gera um quadrado aleatório no centro da tela
     let points = [
@@ -52,10 +55,14 @@ async fn main() {
 
         // Adiciona uma nova partícula no sistema se espaço for pressionado
         if is_key_pressed(KeyCode::Space) {
-            particles.push(Particle::new(
-                Vec2::new(400.0, 300.0),
-                Vec2::new(200.0, 0.0).rotated(randf_range(-PI, PI)),
-            ));
+            emitter.burst(1);
+        }
+        // Emite partículas continuamente enquanto E estiver pressionado
+        emitter.rate = if is_key_down(KeyCode::E) { 30.0 } else { 0.0 };
+        // Derrama água na posição do mouse enquanto F estiver pressionado
+        if is_key_down(KeyCode::F) {
+            let jitter = Vec2::new(randf_range(-4.0, 4.0), randf_range(-4.0, 4.0));
+            fluid.spawn(mouse_pos + jitter, Vec2::NULL);
         }
 
         // A última reta corresponde à posição do mouse.
@@ -75,16 +82,21 @@ async fn main() {
             line.draw(2.0, color::WHITE);
         }
 
-        // Desenha a partícula
-        for particle in &mut particles {
-            particle.update(delta as f64, &lines);
-            particle.draw(color::RED);
-            particle.draw_movement_line(delta as f64, 2.0, color::BLUE);
+        // Atualiza e desenha as partículas
+        let walls: Vec<Wall> = lines.iter().map(|&line| Wall::from(line)).collect();
+        emitter.update(delta as f64, &walls);
+        fluid.update(delta as f64, &walls);
+        fluid.draw(color::SKYBLUE);
+        for emitted in &emitter.particles {
+            emitted.particle.draw(color::RED);
+            emitted.particle.draw_movement_line(delta as f64, 2.0, color::BLUE);
         }
 
         print("Espaço - Gera nova partícula", 10.0, 10.0, 16, color::WHITE, Some(&nunito));
         print("Clique esquerdo - Muda a posição da linha", 10.0, 26.0, 16, color::WHITE, Some(&nunito));
         print("Enter - Adiciona a nova linha", 10.0, 42.0, 16, color::WHITE, Some(&nunito));
+        print("E (segurar) - Emite partículas continuamente", 10.0, 58.0, 16, color::WHITE, Some(&nunito));
+        print("F (segurar) - Derrama água no mouse", 10.0, 74.0, 16, color::WHITE, Some(&nunito));
         next_frame().await
     }
 }
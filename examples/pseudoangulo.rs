@@ -1,4 +1,8 @@
-use jufi::{algebra::Vec2, physics::shapes::Line, utils::print};
+use jufi::{
+    algebra::{Angle, Vec2},
+    physics::shapes::Line,
+    utils::print,
+};
 use macroquad::{color, prelude::*, rand::srand};
 
 #[macroquad::main("Hello, World!")]
@@ -109,9 +113,10 @@ async fn main() {
 
         print(format!("Ângulo (via produto escalar): {a_vecs_dot}").as_str(), 10.0, 10.0, 16, color::WHITE, Some(&nunito));
         print(format!("Ângulo (via produto vetorial): {a_vecs_cross}").as_str(), 10.0, 26.0, 16, color::WHITE, Some(&nunito));
-        print(format!("Pseudoângulo do cosseno: {a_vecs_pseudo_cos}").as_str(), 10.0, 42.0, 16, color::WHITE, Some(&nunito));
-        print(format!("Pseudoângulo no quadrado: {a_vec1_pseudo_sqr}").as_str(), 10.0, 58.0, 16, color::WHITE, Some(&nunito));
+        print(format!("Pseudoângulo do cosseno: {a_vecs_pseudo_cos} (= {})", Angle::from_cos_pseudoangle(a_vecs_pseudo_cos)).as_str(), 10.0, 42.0, 16, color::WHITE, Some(&nunito));
+        print(format!("Pseudoângulo no quadrado: {a_vec1_pseudo_sqr} (= {})", Angle::from_square_pseudoangle(a_vec1_pseudo_sqr)).as_str(), 10.0, 58.0, 16, color::WHITE, Some(&nunito));
         print(format!("Pseudoângulo entre os vetores no quadrado: {a_vecs_pseudo_sqr}").as_str(), 10.0, 74.0, 16, color::WHITE, Some(&nunito));
+        print(format!("Ângulo do vetor 1 (atan2 rápido): {}", Angle::fast_atan2(line1.p2.y, line1.p2.x)).as_str(), 10.0, 90.0, 16, color::WHITE, Some(&nunito));
 
         next_frame().await
     }
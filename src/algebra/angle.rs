@@ -0,0 +1,263 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::algebra::{Scalar, Vec2};
+
+/// Ângulo em radianos, sempre normalizado para o intervalo `(-π, π]`.
+/// Somar, subtrair ou interpolar ângulos nunca sai desse intervalo, então
+/// 179° + 2° vira -179° sem precisar de `if`s espalhados pelo código.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+pub struct Angle<T = f64>(T);
+
+impl<T: Scalar> Angle<T> {
+    pub const ZERO: Angle<T> = Angle(T::ZERO);
+
+    #[inline]
+    #[must_use]
+    /// Construtor do ângulo a partir de radianos (qualquer valor, é normalizado)
+    pub fn from_radians(radians: T) -> Angle<T> {
+        Angle(wrap(radians))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construtor do ângulo a partir de graus (qualquer valor, é normalizado)
+    pub fn from_degrees(degrees: T) -> Angle<T> {
+        Angle::from_radians(degrees * T::from_f64(PI / 180.0))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Ângulo do vetor em relação ao eixo X (sentido de X para Y).
+    /// `atan2` pode dar -π (por exemplo com `y = -0.0`), que é levado para π.
+    pub fn from_vector(v: Vec2<T>) -> Angle<T> {
+        Angle::from_radians(v.y.atan2(v.x))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o ângulo em radianos, em `(-π, π]`
+    pub fn radians(self) -> T {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o ângulo em graus, em `(-180, 180]`
+    pub fn degrees(self) -> T {
+        self.0 * T::from_f64(180.0 / PI)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o vetor unitário que aponta na direção do ângulo
+    pub fn to_vector(self) -> Vec2<T> {
+        Vec2::from_angle(self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a diferença com sinal pelo menor arco, de `self` até `other`
+    /// (positiva se o menor caminho gira de X para Y)
+    pub fn difference(self, other: Angle<T>) -> Angle<T> {
+        Angle::from_radians(other.0 - self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Interpolação linear do valor em radianos, sem passar pelo corte em ±π
+    /// (de 170° para -170° passa por 0°, o caminho longo)
+    pub fn lerp(self, other: Angle<T>, t: T) -> Angle<T> {
+        Angle::from_radians(self.0 + (other.0 - self.0) * t)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Interpolação pelo menor arco com velocidade angular constante
+    /// (de 170° para -170° passa por 180°, o caminho curto)
+    pub fn slerp(self, other: Angle<T>, t: T) -> Angle<T> {
+        Angle::from_radians(self.0 + self.difference(other).0 * t)
+    }
+
+    #[must_use]
+    /// Converte um pseudoângulo do quadrado (`Vec2::square_pseudoangle`, em `[0,8)`)
+    /// de volta para um ângulo. O pseudoângulo não é proporcional ao ângulo, então a
+    /// diferença entre dois pseudoângulos (`square_pseudoangle_between`) só preserva a
+    /// ordem: para o ângulo entre dois vetores converta cada um e use `difference`.
+    pub fn from_square_pseudoangle(pseudo: T) -> Angle<T> {
+        // Pseudoângulo em [0,8), um octante por unidade
+        let p = pseudo - T::from_f64(8.0) * (pseudo / T::from_f64(8.0)).floor();
+        let two = T::from_f64(2.0);
+        // Ponto correspondente no perímetro do quadrado [-1,1]x[-1,1]
+        let point = if p < T::ONE {
+            Vec2::new(T::ONE, p)
+        } else if p < T::from_f64(3.0) {
+            Vec2::new(two - p, T::ONE)
+        } else if p < T::from_f64(5.0) {
+            Vec2::new(-T::ONE, T::from_f64(4.0) - p)
+        } else if p < T::from_f64(7.0) {
+            Vec2::new(p - T::from_f64(6.0), -T::ONE)
+        } else {
+            Vec2::new(T::ONE, p - T::from_f64(8.0))
+        };
+        Angle::from_vector(point)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o pseudoângulo do quadrado equivalente, em `[0,8)`
+    pub fn square_pseudoangle(self) -> T {
+        self.to_vector().square_pseudoangle()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converte um pseudoângulo do cosseno (`Vec2::cos_pseudoangle_between`, em `[0,2]`)
+    /// de volta para um ângulo. Esse pseudoângulo não tem sinal, então o resultado
+    /// fica sempre em `[0, π]`.
+    pub fn from_cos_pseudoangle(pseudo: T) -> Angle<T> {
+        Angle((T::ONE - pseudo).clamp(-T::ONE, T::ONE).acos())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o pseudoângulo do cosseno equivalente (`1 - cos`), em `[0,2]`
+    pub fn cos_pseudoangle(self) -> T {
+        T::ONE - self.0.cos()
+    }
+
+    #[must_use]
+    /// Aproximação rápida de `atan2(y, x)` com um polinômio de grau 3.
+    /// Erro máximo de ~1.6e-3 rad (~0.09°).
+    pub fn fast_atan2(y: T, x: T) -> Angle<T> {
+        Angle::from_radians(reduce_atan2(y, x, |z| {
+            let az = z.abs();
+            z * T::from_f64(FRAC_PI_4)
+                - z * (az - T::ONE) * (T::from_f64(0.2447) + T::from_f64(0.0663) * az)
+        }))
+    }
+
+    #[must_use]
+    /// Aproximação de `atan2(y, x)` com um polinômio de grau 9
+    /// (Abramowitz & Stegun 4.4.49). Erro máximo de ~1.2e-5 rad (~0.0007°).
+    pub fn precise_atan2(y: T, x: T) -> Angle<T> {
+        Angle::from_radians(reduce_atan2(y, x, |z| {
+            let z2 = z * z;
+            let c = |value: f64| T::from_f64(value);
+            z * (c(0.999866)
+                + z2 * (c(-0.3302995)
+                    + z2 * (c(0.180141) + z2 * (c(-0.085133) + z2 * c(0.0208351)))))
+        }))
+    }
+}
+
+/// (private) Normaliza um ângulo em radianos para `(-π, π]`
+fn wrap<T: Scalar>(radians: T) -> T {
+    let (pi, tau) = (T::from_f64(PI), T::from_f64(TAU));
+    if -pi < radians && radians <= pi {
+        return radians;
+    }
+    // Número de voltas a tirar: ceil((r - π) / 2π)
+    let turns = -((pi - radians) / tau).floor();
+    let wrapped = radians - tau * turns;
+    // Proteção contra o arredondamento levar o resultado para fora do intervalo
+    if wrapped <= -pi {
+        wrapped + tau
+    } else {
+        wrapped
+    }
+}
+
+/// (private) Reduz `atan2(y, x)` para `atan(z)` com `|z| <= 1` e corrige o quadrante
+fn reduce_atan2<T: Scalar>(y: T, x: T, atan: impl Fn(T) -> T) -> T {
+    let (ax, ay) = (x.abs(), y.abs());
+    if ax == T::ZERO && ay == T::ZERO {
+        return T::ZERO;
+    }
+    // No octante perto do eixo Y usa atan(y/x) = π/2 - atan(x/y)
+    let theta = if ax >= ay {
+        atan(ay / ax)
+    } else {
+        T::from_f64(FRAC_PI_2) - atan(ax / ay)
+    };
+    // Leva do primeiro quadrante para o quadrante de (x, y)
+    let theta = if x < T::ZERO {
+        T::from_f64(PI) - theta
+    } else {
+        theta
+    };
+    if y < T::ZERO { -theta } else { theta }
+}
+
+impl<T: Scalar> From<Angle<T>> for Vec2<T> {
+    fn from(angle: Angle<T>) -> Vec2<T> {
+        angle.to_vector()
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Soma de ângulos (normalizada)
+impl<T: Scalar> Add<Angle<T>> for Angle<T> {
+    type Output = Angle<T>;
+    fn add(self, rhs: Angle<T>) -> Angle<T> {
+        Angle::from_radians(self.0 + rhs.0)
+    }
+}
+
+impl<T: Scalar> AddAssign<Angle<T>> for Angle<T> {
+    fn add_assign(&mut self, rhs: Angle<T>) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtração de ângulos (normalizada; para o menor arco use `difference`)
+impl<T: Scalar> Sub<Angle<T>> for Angle<T> {
+    type Output = Angle<T>;
+    fn sub(self, rhs: Angle<T>) -> Angle<T> {
+        Angle::from_radians(self.0 - rhs.0)
+    }
+}
+
+impl<T: Scalar> SubAssign<Angle<T>> for Angle<T> {
+    fn sub_assign(&mut self, rhs: Angle<T>) {
+        *self = *self - rhs;
+    }
+}
+
+/// Multiplicação por escalar (normalizada)
+impl<T: Scalar> Mul<T> for Angle<T> {
+    type Output = Angle<T>;
+    fn mul(self, rhs: T) -> Angle<T> {
+        Angle::from_radians(self.0 * rhs)
+    }
+}
+
+/// Ângulo oposto (π continua π, o intervalo é fechado só em cima)
+impl<T: Scalar> Neg for Angle<T> {
+    type Output = Angle<T>;
+    fn neg(self) -> Angle<T> {
+        Angle::from_radians(-self.0)
+    }
+}
+
+/// Mostra o ângulo em graus
+impl<T: Scalar> Display for Angle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}°", self.degrees())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_pi_maps_to_pi() {
+        let v = Vec2::new(-1.0, -0.0);
+        assert_eq!(Angle::from_vector(v).radians(), PI);
+        assert_eq!(Angle::fast_atan2(-1e-300, -1.0).radians(), PI);
+        assert_eq!(Angle::precise_atan2(-1e-300, -1.0).radians(), PI);
+        assert_eq!(Angle::from_radians(-PI).radians(), PI);
+    }
+}
@@ -0,0 +1,331 @@
+//! Ordenação angular e varredura ao redor de um ponto usando o pseudoângulo do
+//! quadrado (`Vec2::square_pseudoangle`). O pseudoângulo cresce junto com o ângulo
+//! e custa só uma divisão, sem `atan2`, então serve de chave para ordenar.
+//! Empates (direções iguais depois do arredondamento) são resolvidos com o predicado
+//! de orientação exato e depois pela distância, então pontos colineares com o pivô
+//! ficam juntos e do mais perto para o mais longe.
+
+use std::cmp::Ordering;
+
+use crate::algebra::{Orientation, Scalar, Vec2};
+
+/// Compara dois pontos pela ordem angular ao redor de `pivot`, girando de X para Y
+/// a partir do eixo +X. Pontos na mesma direção são ordenados pela distância ao pivô
+/// e pontos iguais ao pivô vêm antes de todos.
+pub fn compare_by_angle<T: Scalar>(pivot: Vec2<T>, a: Vec2<T>, b: Vec2<T>) -> Ordering {
+    let (da, db) = (a - pivot, b - pivot);
+    // O pivô não tem direção: vem primeiro
+    match (da == Vec2::NULL, db == Vec2::NULL) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => {}
+    }
+    compare_keys(
+        pivot,
+        (a, da.square_pseudoangle(), da.length_squared()),
+        (b, db.square_pseudoangle(), db.length_squared()),
+    )
+}
+
+/// Ordena pontos pela ordem angular ao redor de `pivot` (veja `compare_by_angle`)
+pub fn sort_by_angle<T: Scalar>(points: &mut [Vec2<T>], pivot: Vec2<T>) {
+    points.sort_by(|a, b| compare_by_angle(pivot, *a, *b));
+}
+
+#[must_use]
+/// Retorna os índices dos pontos na ordem angular ao redor de `pivot`, para ordenar
+/// dados associados aos pontos. A ordenação é estável: pontos iguais mantêm a ordem.
+pub fn angular_order<T: Scalar>(points: &[Vec2<T>], pivot: Vec2<T>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|i, j| compare_by_angle(pivot, points[*i], points[*j]));
+    order
+}
+
+#[must_use]
+/// Fecho convexo (varredura de Graham sobre a ordem angular). Os vértices saem
+/// girando de X para Y, a partir do ponto de menor `y`, sem pontos colineares;
+/// um ou dois pontos se o fecho for degenerado.
+pub fn convex_hull<T: Scalar>(points: &[Vec2<T>]) -> Vec<Vec2<T>> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    // O pivô é o ponto mais baixo: os outros ficam todos em ângulos de [0, π)
+    let pivot = points[0];
+    sort_by_angle(&mut points[1..], pivot);
+
+    let mut hull: Vec<Vec2<T>> = Vec::with_capacity(points.len());
+    for p in points {
+        // Descarta os pontos que não fazem uma curva de X para Y (inclusive colineares)
+        while let [.., a, b] = hull[..]
+            && T::orientation(a, b, p) != Orientation::CounterClockwise
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    hull
+}
+
+/// Evento da varredura angular: um ponto e o dado associado a ele
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SweepEvent<T = f64, D = usize> {
+    pub point: Vec2<T>,
+    /// Pseudoângulo do quadrado da direção centro -> ponto, em `[0,8)`
+    pub pseudoangle: T,
+    /// Distância ao quadrado até o centro
+    pub distance_squared: T,
+    pub data: D,
+}
+
+/// Varredura angular ao redor de um ponto: os eventos ficam ordenados pelo ângulo
+/// (de X para Y, a partir do eixo +X) e podem ser percorridos a partir de qualquer
+/// direção, por setores (radar) ou agrupados por raio (visibilidade).
+/// Pontos iguais ao centro não têm direção e ficam fora da varredura.
+#[derive(Clone, PartialEq, Debug)]
+pub struct AngularSweep<T = f64, D = usize> {
+    center: Vec2<T>,
+    events: Vec<SweepEvent<T, D>>,
+}
+
+impl<T: Scalar, D> AngularSweep<T, D> {
+    #[must_use]
+    /// Constrói a varredura a partir de pares (ponto, dado). A ordenação é estável:
+    /// eventos no mesmo ponto mantêm a ordem em que foram passados.
+    pub fn new(
+        center: Vec2<T>,
+        items: impl IntoIterator<Item = (Vec2<T>, D)>,
+    ) -> AngularSweep<T, D> {
+        let mut events: Vec<SweepEvent<T, D>> = items
+            .into_iter()
+            .filter(|(point, _)| *point != center)
+            .map(|(point, data)| {
+                let d = point - center;
+                SweepEvent {
+                    point,
+                    pseudoangle: d.square_pseudoangle(),
+                    distance_squared: d.length_squared(),
+                    data,
+                }
+            })
+            .collect();
+        events.sort_by(|a, b| compare_keys(center, a.key(), b.key()));
+        AngularSweep { center, events }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o centro da varredura
+    pub fn center(&self) -> Vec2<T> {
+        self.center
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o número de eventos
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se a varredura não tem eventos
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna os eventos na ordem angular
+    pub fn events(&self) -> &[SweepEvent<T, D>] {
+        &self.events
+    }
+
+    /// Itera sobre os eventos na ordem angular, a partir do eixo +X
+    pub fn iter(&self) -> impl Iterator<Item = &SweepEvent<T, D>> {
+        self.events.iter()
+    }
+
+    /// Itera sobre todos os eventos na ordem angular, dando uma volta completa a
+    /// partir de uma direção (os eventos exatamente nessa direção vêm primeiro)
+    pub fn iter_from(&self, direction: Vec2<T>) -> impl Iterator<Item = &SweepEvent<T, D>> {
+        let start = self.lower_bound(direction);
+        self.events[start..].iter().chain(&self.events[..start])
+    }
+
+    /// Itera sobre os eventos do setor que vai da direção `from` até a direção `to`,
+    /// girando de X para Y (inclui `from` e exclui `to`). Para um radar, chame a cada
+    /// frame com a direção anterior e a atual do feixe. Vazio se as direções forem iguais.
+    pub fn sector(&self, from: Vec2<T>, to: Vec2<T>) -> impl Iterator<Item = &SweepEvent<T, D>> {
+        let (start, end) = (self.lower_bound(from), self.lower_bound(to));
+        let (first, second) = if start <= end {
+            (&self.events[start..end], &self.events[..0])
+        } else {
+            // O setor passa pelo eixo +X: pega o fim e o começo da lista
+            (&self.events[start..], &self.events[..end])
+        };
+        first.iter().chain(second)
+    }
+
+    /// Itera sobre os raios a partir do centro: cada item são os eventos na mesma
+    /// direção, do mais perto para o mais longe. Para visibilidade, o primeiro
+    /// evento de cada raio é o único visível a partir do centro.
+    pub fn rays(&self) -> impl Iterator<Item = &[SweepEvent<T, D>]> {
+        self.events.chunk_by(|a, b| {
+            a.pseudoangle == b.pseudoangle
+                && T::orientation(self.center, a.point, b.point) == Orientation::Collinear
+        })
+    }
+
+    /// (private) Índice do primeiro evento com ângulo maior ou igual ao da direção
+    fn lower_bound(&self, direction: Vec2<T>) -> usize {
+        let pseudo = direction.square_pseudoangle();
+        let point = self.center + direction;
+        self.events.partition_point(|e| {
+            e.pseudoangle < pseudo
+                || (e.pseudoangle == pseudo
+                    && T::orientation(self.center, e.point, point) == Orientation::CounterClockwise)
+        })
+    }
+}
+
+impl<T: Scalar> AngularSweep<T, usize> {
+    #[must_use]
+    /// Constrói a varredura a partir de uma lista de pontos; o dado de cada evento
+    /// é o índice do ponto na lista
+    pub fn from_points(center: Vec2<T>, points: &[Vec2<T>]) -> AngularSweep<T, usize> {
+        AngularSweep::new(center, points.iter().copied().zip(0..))
+    }
+}
+
+impl<T: Scalar, D> SweepEvent<T, D> {
+    /// (private) Chave de ordenação (ponto, pseudoângulo, distância ao quadrado)
+    fn key(&self) -> (Vec2<T>, T, T) {
+        (self.point, self.pseudoangle, self.distance_squared)
+    }
+}
+
+/// (private) Compara chaves (ponto, pseudoângulo, distância ao quadrado) de pontos
+/// diferentes do pivô: pelo pseudoângulo, depois pela orientação exata e depois
+/// pela distância. O pseudoângulo nunca inverte a ordem real dos ângulos, só pode
+/// empatar direções muito próximas, que a orientação exata desempata.
+fn compare_keys<T: Scalar>(pivot: Vec2<T>, a: (Vec2<T>, T, T), b: (Vec2<T>, T, T)) -> Ordering {
+    let ((a, a_pseudo, a_dist), (b, b_pseudo, b_dist)) = (a, b);
+    a_pseudo
+        .total_cmp(&b_pseudo)
+        .then_with(|| match T::orientation(pivot, a, b) {
+            Orientation::CounterClockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        })
+        .then_with(|| a_dist.total_cmp(&b_dist))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: f64, y: f64) -> Vec2 {
+        Vec2::new(x, y)
+    }
+
+    #[test]
+    fn sort_by_angle_breaks_ties() {
+        // Os três perto de +Y têm o mesmo pseudoângulo (2.0 depois do arredondamento) e a
+        // mesma distância: só a orientação exata separa. Os colineares vão por distância.
+        let mut points = vec![
+            v(0.0, 2.0),
+            v(0.0, 1.0),
+            v(1e-17, 1.0),
+            v(2e-17, 1.0),
+            v(3.0, 0.0),
+            v(1.0, 0.0),
+            v(0.0, 0.0),
+        ];
+        assert_eq!(
+            angular_order(&points, Vec2::NULL),
+            vec![6, 5, 4, 3, 2, 1, 0]
+        );
+        sort_by_angle(&mut points, Vec2::NULL);
+        let expected = [
+            v(0.0, 0.0),
+            v(1.0, 0.0),
+            v(3.0, 0.0),
+            v(2e-17, 1.0),
+            v(1e-17, 1.0),
+            v(0.0, 1.0),
+            v(0.0, 2.0),
+        ];
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn convex_hull_skips_collinear_and_duplicates() {
+        // Quadrado com os pontos médios dos lados, o centro e pontos repetidos
+        let points = [
+            v(0.0, 0.0),
+            v(1.0, 0.0),
+            v(2.0, 0.0),
+            v(2.0, 1.0),
+            v(2.0, 2.0),
+            v(1.0, 2.0),
+            v(0.0, 2.0),
+            v(0.0, 1.0),
+            v(1.0, 1.0),
+            v(2.0, 2.0),
+            v(0.0, 0.0),
+        ];
+        let square = vec![v(0.0, 0.0), v(2.0, 0.0), v(2.0, 2.0), v(0.0, 2.0)];
+        assert_eq!(convex_hull(&points), square);
+
+        // Fechos degenerados: só as pontas do segmento, ou o único ponto
+        let line = [
+            v(1.0, 1.0),
+            v(0.0, 0.0),
+            v(3.0, 3.0),
+            v(2.0, 2.0),
+            v(1.0, 1.0),
+        ];
+        assert_eq!(convex_hull(&line), vec![v(0.0, 0.0), v(3.0, 3.0)]);
+        assert_eq!(convex_hull(&[v(1.0, 1.0); 3]), vec![v(1.0, 1.0)]);
+    }
+
+    #[test]
+    fn sweep_wraps_around_the_x_axis() {
+        // Pseudoângulos 0, 2, 4, 6 e 7.5
+        let points = [
+            v(1.0, 0.0),
+            v(0.0, 1.0),
+            v(-1.0, 0.0),
+            v(0.0, -1.0),
+            v(1.0, -0.5),
+        ];
+        let sweep = AngularSweep::from_points(Vec2::NULL, &points);
+        let data = |events: Vec<&SweepEvent>| events.iter().map(|e| e.data).collect::<Vec<_>>();
+
+        // De -Y até +Y passando por +X; `to` fica de fora
+        assert_eq!(
+            data(sweep.sector(v(0.0, -1.0), v(0.0, 1.0)).collect()),
+            [3, 4, 0]
+        );
+        assert_eq!(
+            data(sweep.sector(v(0.0, -1.0), v(1.0, 0.0)).collect()),
+            [3, 4]
+        );
+        assert!(sweep.sector(v(1.0, 0.0), v(2.0, 0.0)).next().is_none());
+
+        assert_eq!(
+            data(sweep.iter_from(v(2.0, -1.0)).collect()),
+            [4, 0, 1, 2, 3]
+        );
+        // Depois do último evento (pseudoângulo 7.75): volta completa a partir de +X
+        assert_eq!(
+            data(sweep.iter_from(v(1.0, -0.25)).collect()),
+            [0, 1, 2, 3, 4]
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use crate::algebra::{Mat3, Scalar, Vec2};
+
+/// Número de acumuladores independentes nos laços de redução. Com vários acumuladores
+/// o compilador consegue usar instruções SIMD (sem depender de uma cadeia única de min/max).
+const LANES: usize = 8;
+
+/// Projeta todos os pontos sobre um eixo (produto escalar de cada ponto com o eixo)
+pub fn project<T: Scalar>(points: &[Vec2<T>], axis: Vec2<T>) -> Vec<T> {
+    points.iter().map(|p| p.dot(axis)).collect()
+}
+
+/// Retorna os valores mínimo e máximo das projeções dos pontos sobre um eixo.
+/// Retorna `(INFINITY, -INFINITY)` se não houver pontos.
+pub fn minmax_projection<T: Scalar>(points: &[Vec2<T>], axis: Vec2<T>) -> (T, T) {
+    let chunks = points.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    let mut lanes = Lanes::new();
+    for chunk in chunks {
+        lanes.push(std::array::from_fn(|i| chunk[i].dot(axis)));
+    }
+    lanes.finish(rest.iter().map(|p| p.dot(axis)))
+}
+
+/// Retorna a distância de cada ponto até `point`
+pub fn distances_to<T: Scalar>(points: &[Vec2<T>], point: Vec2<T>) -> Vec<T> {
+    points.iter().map(|p| p.distance_to(point)).collect()
+}
+
+/// Retorna a distância ao quadrado de cada ponto até `point`
+pub fn distances_squared_to<T: Scalar>(points: &[Vec2<T>], point: Vec2<T>) -> Vec<T> {
+    points
+        .iter()
+        .map(|p| p.distance_to_squared(point))
+        .collect()
+}
+
+/// Nuvem de pontos guardada como estrutura de vetores (SoA): todos os `x` juntos
+/// e todos os `y` juntos. Nesse formato as operações em lote são laços simples
+/// sobre arrays contíguos que o compilador vetoriza automaticamente.
+/// Vale a pena quando a mesma nuvem é processada muitas vezes (como em `OBB::enclosing`).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Vec2Batch<T = f64> {
+    pub xs: Vec<T>,
+    pub ys: Vec<T>,
+}
+
+impl<T: Scalar> Vec2Batch<T> {
+    #[inline]
+    #[must_use]
+    /// Construtor do lote vazio
+    pub fn new() -> Vec2Batch<T> {
+        Vec2Batch {
+            xs: Vec::new(),
+            ys: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    /// Constrói o lote a partir de uma lista de pontos
+    pub fn from_points(points: &[Vec2<T>]) -> Vec2Batch<T> {
+        Vec2Batch {
+            xs: points.iter().map(|p| p.x).collect(),
+            ys: points.iter().map(|p| p.y).collect(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o número de pontos
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se o lote não tem pontos
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    #[inline]
+    /// Adiciona um ponto no final do lote
+    pub fn push(&mut self, point: Vec2<T>) {
+        self.xs.push(point.x);
+        self.ys.push(point.y);
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o ponto de índice `i`. Pânico se `i >= len()`
+    pub fn get(&self, i: usize) -> Vec2<T> {
+        Vec2::new(self.xs[i], self.ys[i])
+    }
+
+    /// Itera sobre os pontos do lote
+    pub fn iter(&self) -> impl Iterator<Item = Vec2<T>> + '_ {
+        self.xs.iter().zip(&self.ys).map(|(x, y)| Vec2::new(*x, *y))
+    }
+
+    #[must_use]
+    /// Converte o lote de volta para uma lista de pontos
+    pub fn to_points(&self) -> Vec<Vec2<T>> {
+        self.iter().collect()
+    }
+
+    /// Projeta todos os pontos sobre um eixo, escrevendo em `out`. Pânico se
+    /// `out` tiver um tamanho diferente do lote
+    pub fn project_into(&self, axis: Vec2<T>, out: &mut [T]) {
+        assert_eq!(
+            out.len(),
+            self.len(),
+            "Tamanho da saída deve ser igual ao do lote!"
+        );
+        for ((o, x), y) in out.iter_mut().zip(&self.xs).zip(&self.ys) {
+            *o = *x * axis.x + *y * axis.y;
+        }
+    }
+
+    #[must_use]
+    /// Projeta todos os pontos sobre um eixo
+    pub fn project(&self, axis: Vec2<T>) -> Vec<T> {
+        let mut out = vec![T::ZERO; self.len()];
+        self.project_into(axis, &mut out);
+        out
+    }
+
+    #[must_use]
+    /// Retorna os valores mínimo e máximo das projeções dos pontos sobre um eixo.
+    /// Retorna `(INFINITY, -INFINITY)` se o lote estiver vazio.
+    pub fn minmax_projection(&self, axis: Vec2<T>) -> (T, T) {
+        let xs = self.xs.chunks_exact(LANES);
+        let ys = self.ys.chunks_exact(LANES);
+        let rest = xs.remainder().iter().zip(ys.remainder());
+        let mut lanes = Lanes::new();
+        for (x, y) in xs.zip(ys) {
+            lanes.push(std::array::from_fn(|i| x[i] * axis.x + y[i] * axis.y));
+        }
+        lanes.finish(rest.map(|(x, y)| *x * axis.x + *y * axis.y))
+    }
+
+    /// Move todos os pontos por um deslocamento
+    pub fn translate(&mut self, offset: Vec2<T>) {
+        self.xs.iter_mut().for_each(|x| *x += offset.x);
+        self.ys.iter_mut().for_each(|y| *y += offset.y);
+    }
+
+    #[must_use]
+    /// Retorna a distância ao quadrado de cada ponto até `point`
+    pub fn distances_squared_to(&self, point: Vec2<T>) -> Vec<T> {
+        self.xs
+            .iter()
+            .zip(&self.ys)
+            .map(|(x, y)| {
+                let (dx, dy) = (*x - point.x, *y - point.y);
+                dx * dx + dy * dy
+            })
+            .collect()
+    }
+
+    #[must_use]
+    /// Retorna a distância de cada ponto até `point`
+    pub fn distances_to(&self, point: Vec2<T>) -> Vec<T> {
+        let mut distances = self.distances_squared_to(point);
+        distances.iter_mut().for_each(|d| *d = d.sqrt());
+        distances
+    }
+}
+
+impl Vec2Batch {
+    /// Aplica uma transformação afim em todos os pontos
+    pub fn transform(&mut self, matrix: &Mat3) {
+        let [[a, b, tx], [c, d, ty], _] = matrix.m;
+        for (x, y) in self.xs.iter_mut().zip(self.ys.iter_mut()) {
+            (*x, *y) = (a * *x + b * *y + tx, c * *x + d * *y + ty);
+        }
+    }
+}
+
+impl<T: Scalar> From<&[Vec2<T>]> for Vec2Batch<T> {
+    fn from(points: &[Vec2<T>]) -> Vec2Batch<T> {
+        Vec2Batch::from_points(points)
+    }
+}
+
+impl<T: Scalar> FromIterator<Vec2<T>> for Vec2Batch<T> {
+    fn from_iter<I: IntoIterator<Item = Vec2<T>>>(iter: I) -> Vec2Batch<T> {
+        let mut batch = Vec2Batch::new();
+        for point in iter {
+            batch.push(point);
+        }
+        batch
+    }
+}
+
+/// (private) Acumuladores de mínimo e máximo, um por "faixa" SIMD
+struct Lanes<T> {
+    min: [T; LANES],
+    max: [T; LANES],
+}
+
+impl<T: Scalar> Lanes<T> {
+    fn new() -> Lanes<T> {
+        Lanes {
+            min: [T::INFINITY; LANES],
+            max: [-T::INFINITY; LANES],
+        }
+    }
+
+    /// Acumula um bloco de valores (comparações simples, sem desvios, para vetorizar)
+    fn push(&mut self, values: [T; LANES]) {
+        let lanes = self.min.iter_mut().zip(self.max.iter_mut());
+        for ((min, max), value) in lanes.zip(values) {
+            if value < *min {
+                *min = value;
+            }
+            if value > *max {
+                *max = value;
+            }
+        }
+    }
+
+    /// Junta as faixas e os valores que sobraram fora dos blocos
+    fn finish(self, rest: impl Iterator<Item = T>) -> (T, T) {
+        let lanes = self.min.into_iter().zip(self.max);
+        lanes
+            .chain(rest.map(|value| (value, value)))
+            .fold((T::INFINITY, -T::INFINITY), |(min, max), (lo, hi)| {
+                (min.min(lo), max.max(hi))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::shapes::OBB;
+
+    /// Mínimo e máximo das projeções com um fold simples (sem acumuladores)
+    fn fold_projection(points: &[Vec2], axis: Vec2) -> (f64, f64) {
+        points
+            .iter()
+            .map(|p| p.dot(axis))
+            .fold((f64::INFINITY, -f64::INFINITY), |(min, max), proj| {
+                (min.min(proj), max.max(proj))
+            })
+    }
+
+    /// Nuvem de pontos fixa, com coordenadas negativas e positivas
+    fn cloud(n: usize) -> Vec<Vec2> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64;
+                Vec2::new((t * 1.7).sin() * 5.0 - 1.0, (t * 2.3).cos() * 3.0 - t * 0.4)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn minmax_projection_matches_fold() {
+        let axis = Vec2::new(0.6, -0.8);
+        // Tamanhos vazios, menores, iguais e maiores que um bloco de LANES
+        for n in [0, 1, 7, 8, 9, 17] {
+            let points = cloud(n);
+            let expected = fold_projection(&points, axis);
+            assert_eq!(minmax_projection(&points, axis), expected, "n = {n}");
+            let batch = Vec2Batch::from_points(&points);
+            assert_eq!(batch.minmax_projection(axis), expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn obb_enclosing_is_unchanged() {
+        // OBB calculada como antes dos lotes: fold em cada ângulo, sem paralelismo
+        let points = cloud(23);
+        let expected = (-90..90)
+            .map(|t| {
+                let u = Vec2::from_angle((t as f64).to_radians()).normalized();
+                let v = Vec2::new(-u.y, u.x).normalized();
+                let (min_u, max_u) = fold_projection(&points, u);
+                let (min_v, max_v) = fold_projection(&points, v);
+                let extents = Vec2::new(max_u - min_u, max_v - min_v) / 2.0;
+                let center = (u * (min_u + max_u) + v * (min_v + max_v)) / 2.0;
+                OBB::new(center, extents, u, v)
+            })
+            .min_by(|a, b| a.area().total_cmp(&b.area()))
+            .unwrap();
+        let obb = OBB::enclosing(&points);
+        assert!(obb == expected, "OBB diferente da calculada sem lotes");
+    }
+}
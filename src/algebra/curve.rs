@@ -0,0 +1,663 @@
+use macroquad::color::Color;
+
+use crate::{algebra::Vec2, physics::shapes::Line};
+
+/// Profundidade máxima da subdivisão ao aproximar uma curva por segmentos
+const MAX_FLATTEN_DEPTH: u32 = 16;
+
+/// Número de trechos usados para integrar o comprimento e para a busca inicial do ponto mais próximo
+const SAMPLES: usize = 32;
+
+/// Iterações do método de Newton (ponto mais próximo e reparametrização por comprimento)
+const NEWTON_ITERATIONS: usize = 8;
+
+/// Nós e pesos da quadratura de Gauss-Legendre com 5 pontos em [-1, 1]
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// Uma curva paramétrica em 2D, com o parâmetro `t` indo de 0 (início) a 1 (fim).
+pub trait Curve {
+    /// Retorna o ponto da curva em `t`
+    fn point(&self, t: f64) -> Vec2;
+
+    /// Retorna a derivada (vetor tangente, não normalizado) em `t`
+    fn derivative(&self, t: f64) -> Vec2;
+
+    /// Retorna a segunda derivada em `t`
+    fn second_derivative(&self, t: f64) -> Vec2;
+
+    /// Retorna a direção unitária da curva em `t`. Onde a derivada se anula (por
+    /// exemplo em `t = 0` de uma Bézier com `p0 == p1`) usa a segunda derivada, que dá
+    /// a direção em que a curva sai do ponto, e depois a corda do início ao fim.
+    /// Retorna `Vec2::NULL` só se a curva inteira for um ponto.
+    fn tangent(&self, t: f64) -> Vec2 {
+        let d = self.derivative(t);
+        let dd = self.second_derivative(t);
+        let direction = if d.length() > f64::EPSILON * dd.length() {
+            d
+        } else if dd.length() > 0.0 {
+            // Perto de t: d(t + h) ≈ h * dd. No fim da curva ela chega por h < 0
+            if t >= 1.0 { -dd } else { dd }
+        } else {
+            self.point(1.0) - self.point(0.0)
+        };
+        if direction.length() > 0.0 {
+            direction.normalized()
+        } else {
+            Vec2::NULL
+        }
+    }
+
+    /// Retorna a normal unitária da curva em `t` (mesma convenção de `Line::normal`)
+    fn normal(&self, t: f64) -> Vec2 {
+        let d = self.tangent(t);
+        Vec2::new(d.y, -d.x)
+    }
+
+    /// Aproxima a curva por pontos de forma que nenhum trecho se afaste mais que
+    /// `tolerance` da reta entre seus pontos (subdivisão adaptativa: trechos retos
+    /// usam poucos pontos e trechos curvos usam mais)
+    fn polyline(&self, tolerance: f64) -> Vec<Vec2> {
+        let mut points = vec![self.point(0.0)];
+        flatten_range(self, 0.0, 1.0, tolerance, MAX_FLATTEN_DEPTH, &mut points);
+        points
+    }
+
+    /// Aproxima a curva por segmentos de reta (ver `polyline`), por exemplo
+    /// para usar como paredes curvas
+    fn flatten(&self, tolerance: f64) -> Vec<Line> {
+        self.polyline(tolerance)
+            .windows(2)
+            .map(|w| Line::new(w[0], w[1]))
+            .collect()
+    }
+
+    /// Retorna o comprimento da curva entre os parâmetros `t0` e `t1`
+    /// (quadratura de Gauss-Legendre em vários trechos)
+    fn length_between(&self, t0: f64, t1: f64) -> f64 {
+        let step = (t1 - t0) / SAMPLES as f64;
+        (0..SAMPLES)
+            .map(|i| gauss_legendre(self, t0 + step * i as f64, t0 + step * (i + 1) as f64))
+            .sum()
+    }
+
+    /// Retorna o comprimento total da curva
+    fn length(&self) -> f64 {
+        self.length_between(0.0, 1.0)
+    }
+
+    /// Retorna o parâmetro `t` e o ponto da curva mais próximos de `point`.
+    /// Faz uma busca grossa em amostras e refina com o método de Newton.
+    fn closest_point(&self, point: Vec2) -> (f64, Vec2) {
+        let distance = |t: f64| self.point(t).distance_to_squared(point);
+        let mut t = (0..=SAMPLES)
+            .map(|i| i as f64 / SAMPLES as f64)
+            .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+            .unwrap_or(0.0);
+
+        // Newton em f(t) = (C(t) - p) . C'(t), que é zero no ponto mais próximo
+        for _ in 0..NEWTON_ITERATIONS {
+            let offset = self.point(t) - point;
+            let d1 = self.derivative(t);
+            let f = offset.dot(d1);
+            let df = d1.dot(d1) + offset.dot(self.second_derivative(t));
+            if df.abs() <= f64::EPSILON {
+                break;
+            }
+            let next = (t - f / df).clamp(0.0, 1.0);
+            // Newton pode piorar em pontos de inflexão: só aceita se aproximar
+            if distance(next) > distance(t) {
+                break;
+            }
+            t = next;
+        }
+        (t, self.point(t))
+    }
+
+    /// Desenha a curva aproximada por segmentos
+    fn draw(&self, tolerance: f64, thickness: f32, color: Color) {
+        for line in self.flatten(tolerance) {
+            line.draw(thickness, color);
+        }
+    }
+}
+
+/// (private) Comprimento da curva em `[t0, t1]` com uma única quadratura de 5 pontos
+fn gauss_legendre<C: Curve + ?Sized>(curve: &C, t0: f64, t1: f64) -> f64 {
+    let (mid, half) = ((t0 + t1) / 2.0, (t1 - t0) / 2.0);
+    GAUSS_LEGENDRE
+        .iter()
+        .map(|(x, w)| w * curve.derivative(mid + half * x).length())
+        .sum::<f64>()
+        * half
+}
+
+/// (private) Subdivide o intervalo `[t0, t1]` até a curva ficar próxima da reta
+fn flatten_range<C: Curve + ?Sized>(
+    curve: &C,
+    t0: f64,
+    t1: f64,
+    tolerance: f64,
+    depth: u32,
+    points: &mut Vec<Vec2>,
+) {
+    let chord = Line::new(curve.point(t0), curve.point(t1));
+    // Testa alguns pontos internos: só o meio não basta para curvas em "S"
+    let flat = [0.25, 0.5, 0.75].iter().all(|s| {
+        let p = curve.point(t0 + (t1 - t0) * s);
+        chord.closest_point(p).distance_to_squared(p) <= tolerance * tolerance
+    });
+    if flat || depth == 0 {
+        points.push(chord.p2);
+        return;
+    }
+    let mid = (t0 + t1) / 2.0;
+    flatten_range(curve, t0, mid, tolerance, depth - 1, points);
+    flatten_range(curve, mid, t1, tolerance, depth - 1, points);
+}
+
+/// Curva de Bézier quadrática (3 pontos de controle)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct QuadraticBezier {
+    pub p0: Vec2,
+    pub p1: Vec2,
+    pub p2: Vec2,
+}
+
+impl QuadraticBezier {
+    #[inline]
+    #[must_use]
+    /// Construtor da curva
+    pub fn new(p0: Vec2, p1: Vec2, p2: Vec2) -> QuadraticBezier {
+        QuadraticBezier { p0, p1, p2 }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converte para a Bézier cúbica equivalente (elevação de grau)
+    pub fn to_cubic(&self) -> CubicBezier {
+        CubicBezier::new(
+            self.p0,
+            self.p0 + (self.p1 - self.p0) * (2.0 / 3.0),
+            self.p2 + (self.p1 - self.p2) * (2.0 / 3.0),
+            self.p2,
+        )
+    }
+}
+
+impl Curve for QuadraticBezier {
+    fn point(&self, t: f64) -> Vec2 {
+        let s = 1.0 - t;
+        self.p0 * (s * s) + self.p1 * (2.0 * s * t) + self.p2 * (t * t)
+    }
+
+    fn derivative(&self, t: f64) -> Vec2 {
+        ((self.p1 - self.p0) * (1.0 - t) + (self.p2 - self.p1) * t) * 2.0
+    }
+
+    fn second_derivative(&self, _t: f64) -> Vec2 {
+        (self.p2 - self.p1 * 2.0 + self.p0) * 2.0
+    }
+}
+
+/// Curva de Bézier cúbica (4 pontos de controle)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CubicBezier {
+    pub p0: Vec2,
+    pub p1: Vec2,
+    pub p2: Vec2,
+    pub p3: Vec2,
+}
+
+impl CubicBezier {
+    #[inline]
+    #[must_use]
+    /// Construtor da curva
+    pub fn new(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2) -> CubicBezier {
+        CubicBezier { p0, p1, p2, p3 }
+    }
+
+    #[must_use]
+    /// Divide a curva em duas no parâmetro `t` (algoritmo de De Casteljau)
+    pub fn split(&self, t: f64) -> (CubicBezier, CubicBezier) {
+        let lerp = |a: Vec2, b: Vec2| a + (b - a) * t;
+        let (a, b, c) = (
+            lerp(self.p0, self.p1),
+            lerp(self.p1, self.p2),
+            lerp(self.p2, self.p3),
+        );
+        let (d, e) = (lerp(a, b), lerp(b, c));
+        let f = lerp(d, e);
+        (
+            CubicBezier::new(self.p0, a, d, f),
+            CubicBezier::new(f, e, c, self.p3),
+        )
+    }
+}
+
+impl Curve for CubicBezier {
+    fn point(&self, t: f64) -> Vec2 {
+        let s = 1.0 - t;
+        self.p0 * (s * s * s)
+            + self.p1 * (3.0 * s * s * t)
+            + self.p2 * (3.0 * s * t * t)
+            + self.p3 * (t * t * t)
+    }
+
+    fn derivative(&self, t: f64) -> Vec2 {
+        let s = 1.0 - t;
+        ((self.p1 - self.p0) * (s * s)
+            + (self.p2 - self.p1) * (2.0 * s * t)
+            + (self.p3 - self.p2) * (t * t))
+            * 3.0
+    }
+
+    fn second_derivative(&self, t: f64) -> Vec2 {
+        let a = self.p2 - self.p1 * 2.0 + self.p0;
+        let b = self.p3 - self.p2 * 2.0 + self.p1;
+        (a * (1.0 - t) + b * t) * 6.0
+    }
+}
+
+/// Spline de Catmull-Rom uniforme: passa por todos os pontos, com tangente em cada
+/// ponto paralela à reta entre os vizinhos. Precisa de pelo menos 2 pontos.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CatmullRom {
+    pub points: Vec<Vec2>,
+    /// Se `true`, a curva volta do último ponto para o primeiro
+    pub closed: bool,
+}
+
+impl CatmullRom {
+    #[inline]
+    #[must_use]
+    /// Construtor da spline aberta
+    pub fn new(points: Vec<Vec2>) -> CatmullRom {
+        CatmullRom {
+            points,
+            closed: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construtor da spline fechada (um laço)
+    pub fn closed(points: Vec<Vec2>) -> CatmullRom {
+        CatmullRom {
+            points,
+            closed: true,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o número de trechos (um por par de pontos consecutivos)
+    pub fn segment_count(&self) -> usize {
+        let n = self.points.len();
+        if self.closed { n } else { n.saturating_sub(1) }
+    }
+
+    #[must_use]
+    /// Retorna o trecho `i` da spline como Bézier cúbica. Pânico se `i >= segment_count()`
+    pub fn segment(&self, i: usize) -> CubicBezier {
+        assert!(i < self.segment_count(), "Trecho fora da spline!");
+        let n = self.points.len() as isize;
+        // Nas pontas de uma spline aberta repete o ponto da ponta
+        let at = |j: isize| {
+            let j = if self.closed {
+                j.rem_euclid(n)
+            } else {
+                j.clamp(0, n - 1)
+            };
+            self.points[j as usize]
+        };
+        let i = i as isize;
+        let (p0, p1, p2, p3) = (at(i - 1), at(i), at(i + 1), at(i + 2));
+        CubicBezier::new(p1, p1 + (p2 - p0) / 6.0, p2 - (p3 - p1) / 6.0, p2)
+    }
+
+    #[must_use]
+    /// Retorna todos os trechos da spline como Béziers cúbicas
+    pub fn segments(&self) -> Vec<CubicBezier> {
+        (0..self.segment_count()).map(|i| self.segment(i)).collect()
+    }
+}
+
+impl Curve for CatmullRom {
+    fn point(&self, t: f64) -> Vec2 {
+        piecewise(self.segment_count(), t, |i, u| self.segment(i).point(u))
+    }
+
+    fn derivative(&self, t: f64) -> Vec2 {
+        let n = self.segment_count();
+        piecewise(n, t, |i, u| self.segment(i).derivative(u)) * n as f64
+    }
+
+    fn second_derivative(&self, t: f64) -> Vec2 {
+        let n = self.segment_count();
+        piecewise(n, t, |i, u| self.segment(i).second_derivative(u)) * (n * n) as f64
+    }
+}
+
+/// B-spline cúbica uniforme: suave até a segunda derivada, mas não passa pelos
+/// pontos de controle (só se aproxima deles). Uma spline aberta precisa de pelo
+/// menos 4 pontos.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BSpline {
+    pub points: Vec<Vec2>,
+    /// Se `true`, a curva volta do último ponto para o primeiro
+    pub closed: bool,
+}
+
+impl BSpline {
+    #[inline]
+    #[must_use]
+    /// Construtor da spline aberta
+    pub fn new(points: Vec<Vec2>) -> BSpline {
+        BSpline {
+            points,
+            closed: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construtor da spline fechada (um laço)
+    pub fn closed(points: Vec<Vec2>) -> BSpline {
+        BSpline {
+            points,
+            closed: true,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o número de trechos (um por janela de 4 pontos consecutivos)
+    pub fn segment_count(&self) -> usize {
+        let n = self.points.len();
+        if self.closed { n } else { n.saturating_sub(3) }
+    }
+
+    #[must_use]
+    /// Retorna o trecho `i` da spline como Bézier cúbica. Pânico se `i >= segment_count()`
+    pub fn segment(&self, i: usize) -> CubicBezier {
+        assert!(i < self.segment_count(), "Trecho fora da spline!");
+        let at = |j: usize| self.points[j % self.points.len()];
+        let (p0, p1, p2, p3) = (at(i), at(i + 1), at(i + 2), at(i + 3));
+        CubicBezier::new(
+            (p0 + p1 * 4.0 + p2) / 6.0,
+            (p1 * 2.0 + p2) / 3.0,
+            (p1 + p2 * 2.0) / 3.0,
+            (p1 + p2 * 4.0 + p3) / 6.0,
+        )
+    }
+
+    #[must_use]
+    /// Retorna todos os trechos da spline como Béziers cúbicas
+    pub fn segments(&self) -> Vec<CubicBezier> {
+        (0..self.segment_count()).map(|i| self.segment(i)).collect()
+    }
+}
+
+impl Curve for BSpline {
+    fn point(&self, t: f64) -> Vec2 {
+        piecewise(self.segment_count(), t, |i, u| self.segment(i).point(u))
+    }
+
+    fn derivative(&self, t: f64) -> Vec2 {
+        let n = self.segment_count();
+        piecewise(n, t, |i, u| self.segment(i).derivative(u)) * n as f64
+    }
+
+    fn second_derivative(&self, t: f64) -> Vec2 {
+        let n = self.segment_count();
+        piecewise(n, t, |i, u| self.segment(i).second_derivative(u)) * (n * n) as f64
+    }
+}
+
+/// (private) Avalia uma curva feita de `n` trechos, levando o `t` global para o `t`
+/// do trecho. Sem trechos (pontos insuficientes) retorna o vetor nulo.
+fn piecewise(n: usize, t: f64, f: impl Fn(usize, f64) -> Vec2) -> Vec2 {
+    if n == 0 {
+        return Vec2::NULL;
+    }
+    let scaled = t.clamp(0.0, 1.0) * n as f64;
+    let i = (scaled as usize).min(n - 1);
+    f(i, scaled - i as f64)
+}
+
+/// Curva reparametrizada por comprimento de arco: `point_at(s)` anda `s` unidades
+/// ao longo da curva, independente de como o `t` original se distribui.
+/// Útil para mover objetos com velocidade constante por uma curva.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ArcLength<C> {
+    pub curve: C,
+    /// Parâmetros amostrados e o comprimento acumulado até cada um
+    table: Vec<(f64, f64)>,
+}
+
+impl<C: Curve> ArcLength<C> {
+    #[must_use]
+    /// Constrói a tabela de comprimentos com `samples` trechos (mais trechos, busca mais precisa)
+    pub fn new(curve: C, samples: usize) -> ArcLength<C> {
+        let samples = samples.max(1);
+        let mut table = Vec::with_capacity(samples + 1);
+        let mut total = 0.0;
+        table.push((0.0, 0.0));
+        for i in 1..=samples {
+            let (t0, t1) = ((i - 1) as f64 / samples as f64, i as f64 / samples as f64);
+            total += curve.length_between(t0, t1);
+            table.push((t1, total));
+        }
+        ArcLength { curve, table }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o comprimento total da curva
+    pub fn length(&self) -> f64 {
+        self.table.last().map_or(0.0, |(_, s)| *s)
+    }
+
+    #[must_use]
+    /// Retorna o parâmetro `t` da curva que fica a `distance` unidades do início
+    /// (`distance` é limitado a `[0, length()]`)
+    pub fn parameter_at(&self, distance: f64) -> f64 {
+        let distance = distance.clamp(0.0, self.length());
+        // Busca binária na tabela pelo trecho que contém a distância
+        let i = self
+            .table
+            .partition_point(|(_, s)| *s < distance)
+            .clamp(1, self.table.len() - 1);
+        let ((t0, s0), (t1, s1)) = (self.table[i - 1], self.table[i]);
+        let mut t = if s1 > s0 {
+            t0 + (t1 - t0) * (distance - s0) / (s1 - s0)
+        } else {
+            t0
+        };
+        // Refina com Newton: d(s)/dt = |C'(t)|. O trecho da tabela é curto, então
+        // uma quadratura só em [t0, t] basta (em vez dos SAMPLES de `length_between`)
+        for _ in 0..NEWTON_ITERATIONS {
+            let speed = self.curve.derivative(t).length();
+            if speed <= f64::EPSILON {
+                break;
+            }
+            let error = s0 + gauss_legendre(&self.curve, t0, t) - distance;
+            t = (t - error / speed).clamp(t0, t1);
+        }
+        t
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o ponto a `distance` unidades do início da curva
+    pub fn point_at(&self, distance: f64) -> Vec2 {
+        self.curve.point(self.parameter_at(distance))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a direção da curva a `distance` unidades do início
+    pub fn tangent_at(&self, distance: f64) -> Vec2 {
+        self.curve.tangent(self.parameter_at(distance))
+    }
+
+    #[must_use]
+    /// Retorna `count` pontos igualmente espaçados ao longo da curva (incluindo as pontas)
+    pub fn resample(&self, count: usize) -> Vec<Vec2> {
+        let steps = count.max(2) - 1;
+        (0..=steps)
+            .map(|i| self.point_at(self.length() * i as f64 / steps as f64))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_direction(actual: Vec2, expected: Vec2) {
+        assert!(
+            (actual - expected.normalized()).length() < 1e-9,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn tangent_with_repeated_control_points() {
+        let (a, b, c) = (
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 3.0),
+            Vec2::new(4.0, 4.0),
+        );
+
+        // p0 == p1: sai do início na direção de p2
+        let start = CubicBezier::new(a, a, b, c);
+        assert_direction(start.tangent(0.0), b - a);
+        assert_direction(start.normal(0.0), Vec2::new(2.0, 0.0));
+
+        // p2 == p3: chega no fim vindo de p1
+        let end = CubicBezier::new(a, b, c, c);
+        assert_direction(end.tangent(1.0), c - b);
+
+        // p0 == p1 == p2: a segunda derivada também se anula, usa a corda
+        let corner = CubicBezier::new(a, a, a, c);
+        assert_direction(corner.tangent(0.0), c - a);
+
+        assert_direction(QuadraticBezier::new(a, a, c).tangent(0.0), c - a);
+        assert_eq!(CubicBezier::new(a, a, a, a).tangent(0.5), Vec2::NULL);
+    }
+
+    #[test]
+    fn arc_length_tangent_at_degenerate_start() {
+        let curve = CubicBezier::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+        );
+        let arc = ArcLength::new(curve, 64);
+        assert_direction(arc.tangent_at(0.0), Vec2::X);
+        assert!(arc.tangent_at(arc.length()).is_normalized());
+    }
+
+    /// Cúbica em "S", com curvatura variável
+    fn s_curve() -> CubicBezier {
+        CubicBezier::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 6.0),
+            Vec2::new(2.0, -5.0),
+            Vec2::new(7.0, 1.0),
+        )
+    }
+
+    /// Cúbica reta, com o `t` mal distribuído ao longo da reta
+    fn straight_curve() -> CubicBezier {
+        CubicBezier::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.5, 1.5),
+            Vec2::new(3.0, 3.0),
+        )
+    }
+
+    #[test]
+    fn polyline_stays_within_tolerance() {
+        let curve = s_curve();
+        let tolerance = 0.01;
+        let polyline = curve.polyline(tolerance);
+        assert_eq!(polyline.first(), Some(&curve.point(0.0)));
+        assert_eq!(polyline.last(), Some(&curve.point(1.0)));
+        for p in &polyline {
+            assert!(curve.closest_point(*p).1.distance_to(*p) < 1e-9);
+        }
+
+        // Toda a curva fica a menos de `tolerance` de algum segmento
+        let lines = curve.flatten(tolerance);
+        assert_eq!(lines.len(), polyline.len() - 1);
+        for i in 0..=1000 {
+            let p = curve.point(i as f64 / 1000.0);
+            let distance = lines
+                .iter()
+                .map(|line| line.closest_point(p).distance_to(p))
+                .fold(f64::INFINITY, f64::min);
+            assert!(
+                distance <= tolerance,
+                "ponto {p:?} a {distance} da polyline"
+            );
+        }
+    }
+
+    #[test]
+    fn length_of_straight_curve_is_the_chord() {
+        let curve = straight_curve();
+        assert!((curve.length() - curve.p0.distance_to(curve.p3)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn parameter_at_walks_the_distance() {
+        // Na reta, andar `s` ao longo da curva é andar `s` a partir de p0
+        let straight = ArcLength::new(straight_curve(), 16);
+        for i in 0..=10 {
+            let s = straight.length() * i as f64 / 10.0;
+            assert!((straight.point_at(s).distance_to(Vec2::NULL) - s).abs() < 1e-9);
+        }
+
+        let curve = s_curve();
+        let arc = ArcLength::new(curve, 64);
+        assert!((arc.length() - curve.length()).abs() < 1e-9);
+        for i in 0..=10 {
+            let s = arc.length() * i as f64 / 10.0;
+            let t = arc.parameter_at(s);
+            assert!((curve.length_between(0.0, t) - s).abs() < 1e-9, "s = {s}");
+        }
+        assert_eq!(arc.parameter_at(-1.0), 0.0);
+        assert_eq!(arc.parameter_at(arc.length() + 1.0), 1.0);
+    }
+
+    #[test]
+    fn closest_point_projects_on_the_curve() {
+        // Ponto afastado pela normal: o mais próximo é o ponto de origem
+        let curve = s_curve();
+        for t in [0.1, 0.3, 0.6, 0.9] {
+            let p = curve.point(t) + curve.normal(t) * 0.05;
+            let (closest, point) = curve.closest_point(p);
+            assert!((closest - t).abs() < 1e-6, "t = {t}, obtido {closest}");
+            assert!(point.distance_to(curve.point(t)) < 1e-6);
+        }
+
+        // Antes do início da reta: limita em t = 0
+        let straight = straight_curve();
+        assert_eq!(
+            straight.closest_point(Vec2::new(-1.0, -2.0)),
+            (0.0, Vec2::NULL)
+        );
+        let (_, point) = straight.closest_point(Vec2::new(0.0, 3.0));
+        assert!(point.distance_to(Vec2::new(1.5, 1.5)) < 1e-9);
+    }
+}
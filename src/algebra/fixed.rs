@@ -0,0 +1,406 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::algebra::predicates::{self, Orientation};
+use crate::algebra::{Scalar, Vec2};
+
+/// Número de bits da parte fracionária
+const FRAC_BITS: u32 = 32;
+
+/// Número de iterações do CORDIC (uma por bit da parte fracionária)
+const CORDIC_ITERATIONS: usize = 32;
+
+/// atan(2^-i) em Q32.32, para as iterações do CORDIC
+const ATAN_TABLE: [i64; CORDIC_ITERATIONS] = [
+    3373259426, 1991351318, 1052175346, 534100635, 268086748, 134174063, 67103403, 33553749,
+    16777131, 8388597, 4194303, 2097152, 1048576, 524288, 262144, 131072, 65536, 32768, 16384,
+    8192, 4096, 2048, 1024, 512, 256, 128, 64, 32, 16, 8, 4, 2,
+];
+
+/// Inverso do ganho do CORDIC depois de todas as iterações (~0.607252935) em Q32.32
+const CORDIC_GAIN: i64 = 2608131496;
+
+/// Número de ponto fixo Q32.32 (32 bits de parte inteira com sinal e 32 de fração).
+/// Todas as operações, inclusive `sqrt`, `sin`, `cos` e `atan2`, são feitas só com
+/// inteiros, então o resultado é idêntico bit a bit em qualquer máquina e compilador
+/// (útil para simulações em lockstep). As operações saturam em vez de estourar.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << FRAC_BITS);
+    pub const MAX: Fixed = Fixed(i64::MAX);
+    pub const MIN: Fixed = Fixed(-i64::MAX);
+    /// Menor valor positivo representável (2^-32)
+    pub const EPSILON: Fixed = Fixed(1);
+    pub const PI: Fixed = Fixed(13493037705);
+    pub const TAU: Fixed = Fixed(26986075409);
+    pub const FRAC_PI_2: Fixed = Fixed(6746518852);
+
+    #[inline]
+    #[must_use]
+    /// Constrói o número a partir da representação interna (valor * 2^32)
+    pub const fn from_bits(bits: i64) -> Fixed {
+        Fixed(bits)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a representação interna (valor * 2^32)
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Constrói o número a partir de um inteiro
+    pub const fn from_int(value: i32) -> Fixed {
+        Fixed((value as i64) << FRAC_BITS)
+    }
+
+    #[inline]
+    #[must_use]
+    /// (private) Satura um resultado intermediário de 128 bits
+    fn saturate(value: i128) -> Fixed {
+        Fixed(value.clamp(-(i64::MAX as i128), i64::MAX as i128) as i64)
+    }
+
+    /// (private) CORDIC em modo rotação: retorna (cos, sin) de um ângulo em [-π/2, π/2]
+    fn cordic_rotate(angle: i64) -> (i64, i64) {
+        let (mut x, mut y, mut z) = (CORDIC_GAIN, 0i64, angle);
+        for (i, atan) in ATAN_TABLE.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if z >= 0 {
+                (x, y, z) = (x - dx, y + dy, z - atan);
+            } else {
+                (x, y, z) = (x + dx, y - dy, z + atan);
+            }
+        }
+        (x, y)
+    }
+
+    /// (private) Retorna (sin, cos) com redução do ângulo para [-π/2, π/2]
+    fn sin_cos(self) -> (Fixed, Fixed) {
+        // Reduz para [-π, π]
+        let mut angle = self.0 % Fixed::TAU.0;
+        if angle > Fixed::PI.0 {
+            angle -= Fixed::TAU.0;
+        } else if angle < -Fixed::PI.0 {
+            angle += Fixed::TAU.0;
+        }
+        // Fora de [-π/2, π/2]: gira meia volta e troca os sinais
+        let flip = angle.abs() > Fixed::FRAC_PI_2.0;
+        if flip {
+            angle -= Fixed::PI.0 * angle.signum();
+        }
+        let (cos, sin) = Fixed::cordic_rotate(angle);
+        if flip {
+            (Fixed(-sin), Fixed(-cos))
+        } else {
+            (Fixed(sin), Fixed(cos))
+        }
+    }
+}
+
+impl Scalar for Fixed {
+    const ZERO: Fixed = Fixed::ZERO;
+    const ONE: Fixed = Fixed::ONE;
+    const INFINITY: Fixed = Fixed::MAX;
+    const EPSILON: Fixed = Fixed::EPSILON;
+
+    #[inline]
+    fn from_f64(value: f64) -> Fixed {
+        // Multiplicar por potência de 2 e arredondar são operações exatas no IEEE 754
+        Fixed::saturate((value * (1u64 << FRAC_BITS) as f64).round() as i128)
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << FRAC_BITS) as f64
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    fn sqrt(self) -> Fixed {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+        // sqrt(v * 2^32) * 2^16 = sqrt(v * 2^64) = sqrt(v) * 2^32
+        Fixed(((self.0 as u128) << FRAC_BITS).isqrt() as i64)
+    }
+
+    #[inline]
+    fn abs(self) -> Fixed {
+        Fixed(self.0.saturating_abs())
+    }
+
+    #[inline]
+    fn floor(self) -> Fixed {
+        // Zerar a parte fracionária arredonda para baixo (inclusive nos negativos)
+        Fixed(self.0 & !((1 << FRAC_BITS) - 1))
+    }
+
+    fn sin(self) -> Fixed {
+        self.sin_cos().0
+    }
+
+    fn cos(self) -> Fixed {
+        self.sin_cos().1
+    }
+
+    fn asin(self) -> Fixed {
+        let x = Scalar::clamp(self, -Fixed::ONE, Fixed::ONE);
+        x.atan2((Fixed::ONE - x * x).sqrt())
+    }
+
+    fn acos(self) -> Fixed {
+        let x = Scalar::clamp(self, -Fixed::ONE, Fixed::ONE);
+        (Fixed::ONE - x * x).sqrt().atan2(x)
+    }
+
+    fn atan2(self, x: Fixed) -> Fixed {
+        // Casos exatos sobre os eixos
+        match (x.0.signum(), self.0.signum()) {
+            (0, 0) | (1, 0) => return Fixed::ZERO,
+            (-1, 0) => return Fixed::PI,
+            (0, sign) => return Fixed(Fixed::FRAC_PI_2.0 * sign),
+            _ => {}
+        }
+        let (mut x, mut y) = (x.0 as i128, self.0 as i128);
+        // No semiplano esquerdo gira meia volta (o CORDIC só converge para x >= 0)
+        let mut z: i128 = 0;
+        if x < 0 {
+            z = if y >= 0 { Fixed::PI.0 } else { -Fixed::PI.0 } as i128;
+            (x, y) = (-x, -y);
+        }
+        // Aumenta a escala (em 128 bits) para não perder precisão com números pequenos
+        let shift = 96 - (128 - x.max(y.abs()).leading_zeros());
+        (x, y) = (x << shift, y << shift);
+        for (i, atan) in ATAN_TABLE.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if y > 0 {
+                (x, y, z) = (x + dx, y - dy, z + *atan as i128);
+            } else {
+                (x, y, z) = (x - dx, y + dy, z - *atan as i128);
+            }
+        }
+        // Mantém o resultado em (-π, π]
+        if z <= -(Fixed::PI.0 as i128) {
+            z += Fixed::TAU.0 as i128;
+        } else if z > Fixed::PI.0 as i128 {
+            z -= Fixed::TAU.0 as i128;
+        }
+        Fixed(z as i64)
+    }
+
+    #[inline]
+    fn min(self, other: Fixed) -> Fixed {
+        Ord::min(self, other)
+    }
+
+    #[inline]
+    fn max(self, other: Fixed) -> Fixed {
+        Ord::max(self, other)
+    }
+
+    #[inline]
+    fn total_cmp(&self, other: &Fixed) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn orientation(a: Vec2<Fixed>, b: Vec2<Fixed>, c: Vec2<Fixed>) -> Orientation {
+        // Determinante exato em 128 bits (as diferenças têm até 65 bits)
+        let difference = |p: Fixed, q: Fixed| p.0 as i128 - q.0 as i128;
+        let (abx, aby) = (difference(b.x, a.x), difference(b.y, a.y));
+        let (acx, acy) = (difference(c.x, a.x), difference(c.y, a.y));
+        let det = abx
+            .checked_mul(acy)
+            .zip(aby.checked_mul(acx))
+            .and_then(|(left, right)| left.checked_sub(right));
+        match det {
+            Some(det) => det.cmp(&0).into(),
+            // Coordenadas perto do limite de 2^31: usa o predicado adaptativo
+            None => {
+                let to_f64 = |p: Vec2<Fixed>| Vec2::new(p.x.to_f64(), p.y.to_f64());
+                predicates::orientation(to_f64(a), to_f64(b), to_f64(c))
+            }
+        }
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Fixed {
+        Fixed::from_int(value)
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+impl Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Fixed) -> Fixed {
+        Fixed::saturate(self.0 as i128 + rhs.0 as i128)
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, rhs: Fixed) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Fixed) -> Fixed {
+        Fixed::saturate(self.0 as i128 - rhs.0 as i128)
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, rhs: Fixed) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, rhs: Fixed) -> Fixed {
+        Fixed::saturate((self.0 as i128 * rhs.0 as i128) >> FRAC_BITS)
+    }
+}
+
+impl MulAssign for Fixed {
+    fn mul_assign(&mut self, rhs: Fixed) {
+        *self = *self * rhs;
+    }
+}
+
+/// Divisão (dividir por zero satura para o maior valor com o sinal do dividendo)
+impl Div for Fixed {
+    type Output = Fixed;
+    fn div(self, rhs: Fixed) -> Fixed {
+        if rhs.0 == 0 {
+            return Fixed(i64::MAX * self.0.signum());
+        }
+        Fixed::saturate(((self.0 as i128) << FRAC_BITS) / rhs.0 as i128)
+    }
+}
+
+impl DivAssign for Fixed {
+    fn div_assign(&mut self, rhs: Fixed) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Fixed {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+impl Sum for Fixed {
+    fn sum<I: Iterator<Item = Fixed>>(iter: I) -> Fixed {
+        iter.fold(Fixed::ZERO, |a, b| a + b)
+    }
+}
+
+impl Debug for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Fixed({})", self.to_f64())
+    }
+}
+
+impl Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Erro máximo aceito para o CORDIC (o medido fica perto de 4e-9)
+    const CORDIC_TOLERANCE: f64 = 1e-8;
+
+    #[test]
+    fn sin_cos_match_f64() {
+        for i in -7300..=7300 {
+            let angle = Fixed::from_f64(i as f64 * 0.00137);
+            let x = angle.to_f64();
+            assert!(
+                (angle.sin().to_f64() - x.sin()).abs() < CORDIC_TOLERANCE,
+                "sin({x})"
+            );
+            assert!(
+                (angle.cos().to_f64() - x.cos()).abs() < CORDIC_TOLERANCE,
+                "cos({x})"
+            );
+        }
+    }
+
+    #[test]
+    fn atan2_matches_f64() {
+        for scale in [1e-4, 1.0, 1e4, 3e8] {
+            for i in -40..=40 {
+                for j in -40..=40 {
+                    let y = Fixed::from_f64(i as f64 * 0.37 * scale);
+                    let x = Fixed::from_f64(j as f64 * 0.41 * scale);
+                    let expected = y.to_f64().atan2(x.to_f64());
+                    let error = (y.atan2(x).to_f64() - expected).abs();
+                    // ±π são o mesmo ângulo
+                    let error = error.min((error - std::f64::consts::TAU).abs());
+                    assert!(error < CORDIC_TOLERANCE, "atan2({y}, {x})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_matches_f64() {
+        // A raiz inteira arredonda para baixo: erro de no máximo 1 bit fracionário
+        let ulp = Fixed::EPSILON.to_f64();
+        let mut value = 1e-9;
+        while value < 2e9 {
+            let v = Fixed::from_f64(value);
+            assert!(
+                (v.sqrt().to_f64() - v.to_f64().sqrt()).abs() <= ulp,
+                "sqrt({v})"
+            );
+            value *= 1.013;
+        }
+        assert!((Fixed::MAX.sqrt().to_f64() - Fixed::MAX.to_f64().sqrt()).abs() <= ulp);
+        assert_eq!(Fixed::from_int(-4).sqrt(), Fixed::ZERO);
+    }
+
+    #[test]
+    fn saturates_at_limits() {
+        let two = Fixed::from_int(2);
+        assert_eq!(Fixed::MAX + Fixed::ONE, Fixed::MAX);
+        assert_eq!(Fixed::MIN - Fixed::ONE, Fixed::MIN);
+        assert_eq!(Fixed::MAX * two, Fixed::MAX);
+        assert_eq!(Fixed::MIN * two, Fixed::MIN);
+        assert_eq!(Fixed::MAX * -two, Fixed::MIN);
+        assert_eq!(Fixed::MAX / Fixed::EPSILON, Fixed::MAX);
+        assert_eq!(Fixed::ONE / Fixed::ZERO, Fixed::MAX);
+        assert_eq!(-Fixed::ONE / Fixed::ZERO, Fixed::MIN);
+        assert_eq!(-Fixed::MIN, Fixed::MAX);
+        assert_eq!(Fixed::MIN.abs(), Fixed::MAX);
+        assert_eq!(Fixed::from_f64(1e300), Fixed::MAX);
+        assert_eq!(Fixed::from_f64(-1e300), Fixed::MIN);
+
+        // Trigonometria continua definida nos limites
+        for value in [Fixed::MAX, Fixed::MIN] {
+            let (sin, cos) = (value.sin().to_f64(), value.cos().to_f64());
+            assert!((sin * sin + cos * cos - 1.0).abs() < CORDIC_TOLERANCE);
+        }
+        let diagonal = Fixed::MAX.atan2(Fixed::MAX).to_f64();
+        assert!((diagonal - std::f64::consts::FRAC_PI_4).abs() < CORDIC_TOLERANCE);
+    }
+}
@@ -0,0 +1,115 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::algebra::{Mat2, Rot2, Vec2};
+
+/// Transformação rígida (rotação seguida de translação). Leva pontos do espaço
+/// local de um objeto para o mundo: `p_mundo = rotation * p_local + translation`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Isometry2 {
+    pub rotation: Rot2,
+    pub translation: Vec2,
+}
+
+impl Isometry2 {
+    pub const IDENTITY: Isometry2 = Isometry2 {
+        rotation: Rot2::IDENTITY,
+        translation: Vec2::NULL,
+    };
+
+    #[inline]
+    #[must_use]
+    /// Construtor da transformação a partir da posição e do ângulo
+    pub fn new(translation: Vec2, theta: f64) -> Isometry2 {
+        Isometry2 {
+            rotation: Rot2::new(theta),
+            translation,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construtor da transformação a partir da posição e da rotação
+    pub fn from_parts(translation: Vec2, rotation: Rot2) -> Isometry2 {
+        Isometry2 {
+            rotation,
+            translation,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Transformação que só translada
+    pub fn from_translation(translation: Vec2) -> Isometry2 {
+        Isometry2::from_parts(translation, Rot2::IDENTITY)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Leva um ponto do espaço local para o mundo
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.rotation.rotate(point) + self.translation
+    }
+
+    #[inline]
+    #[must_use]
+    /// Leva uma direção do espaço local para o mundo (ignora a translação)
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        self.rotation.rotate(vector)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Leva um ponto do mundo para o espaço local
+    pub fn inverse_transform_point(&self, point: Vec2) -> Vec2 {
+        self.rotation.inverse_rotate(point - self.translation)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Leva uma direção do mundo para o espaço local
+    pub fn inverse_transform_vector(&self, vector: Vec2) -> Vec2 {
+        self.rotation.inverse_rotate(vector)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a transformação inversa (do mundo para o espaço local)
+    pub fn inverse(&self) -> Isometry2 {
+        let rotation = self.rotation.inverse();
+        Isometry2 {
+            rotation,
+            translation: -rotation.rotate(self.translation),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a parte linear da transformação como matriz
+    pub fn matrix(&self) -> Mat2 {
+        Mat2::from_rotation(self.rotation)
+    }
+}
+
+impl Default for Isometry2 {
+    fn default() -> Isometry2 {
+        Isometry2::IDENTITY
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Composição de transformações (aplica `rhs` primeiro)
+impl Mul<Isometry2> for Isometry2 {
+    type Output = Isometry2;
+    fn mul(self, rhs: Isometry2) -> Isometry2 {
+        Isometry2 {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+}
+
+impl MulAssign<Isometry2> for Isometry2 {
+    fn mul_assign(&mut self, rhs: Isometry2) {
+        *self = *self * rhs;
+    }
+}
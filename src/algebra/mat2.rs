@@ -0,0 +1,140 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::algebra::{Rot2, Vec2};
+
+/// Matriz 2x2 guardada por colunas: `x` é a imagem do eixo X e `y` a do eixo Y.
+/// ```text
+/// | x.x  y.x |
+/// | x.y  y.y |
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Mat2 {
+    pub x: Vec2,
+    pub y: Vec2,
+}
+
+impl Mat2 {
+    pub const IDENTITY: Mat2 = Mat2 {
+        x: Vec2::X,
+        y: Vec2::Y,
+    };
+    pub const ZERO: Mat2 = Mat2 {
+        x: Vec2::NULL,
+        y: Vec2::NULL,
+    };
+
+    #[inline]
+    #[must_use]
+    /// Construtor da matriz a partir das colunas
+    pub fn new(x: Vec2, y: Vec2) -> Mat2 {
+        Mat2 { x, y }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construtor da matriz a partir das linhas
+    pub fn from_rows(row1: Vec2, row2: Vec2) -> Mat2 {
+        Mat2::new(Vec2::new(row1.x, row2.x), Vec2::new(row1.y, row2.y))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Matriz de rotação
+    pub fn from_rotation(rotation: Rot2) -> Mat2 {
+        Mat2::new(rotation.x_axis(), rotation.y_axis())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Matriz de escala (diagonal)
+    pub fn from_scale(scale: Vec2) -> Mat2 {
+        Mat2::new(Vec2::X * scale.x, Vec2::Y * scale.y)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o determinante (fator de área da transformação)
+    pub fn determinant(&self) -> f64 {
+        self.x.cross(self.y)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a matriz transposta
+    pub fn transpose(&self) -> Mat2 {
+        Mat2::from_rows(self.x, self.y)
+    }
+
+    #[must_use]
+    /// Retorna a matriz inversa (None se a matriz for singular, veja `Mat3::inverse`)
+    pub fn inverse(&self) -> Option<Mat2> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        Some(Mat2::new(
+            Vec2::new(self.y.y, -self.x.y) / det,
+            Vec2::new(-self.y.x, self.x.x) / det,
+        ))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Aplica a matriz em um vetor
+    pub fn transform(&self, v: Vec2) -> Vec2 {
+        self.x * v.x + self.y * v.y
+    }
+}
+
+impl Default for Mat2 {
+    fn default() -> Mat2 {
+        Mat2::IDENTITY
+    }
+}
+
+impl From<Rot2> for Mat2 {
+    fn from(rotation: Rot2) -> Mat2 {
+        Mat2::from_rotation(rotation)
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Produto de matrizes (aplica `rhs` primeiro)
+impl Mul<Mat2> for Mat2 {
+    type Output = Mat2;
+    fn mul(self, rhs: Mat2) -> Mat2 {
+        Mat2::new(self.transform(rhs.x), self.transform(rhs.y))
+    }
+}
+
+/// Matriz vezes vetor
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        self.transform(rhs)
+    }
+}
+
+/// Multiplicação por escalar
+impl Mul<f64> for Mat2 {
+    type Output = Mat2;
+    fn mul(self, rhs: f64) -> Mat2 {
+        Mat2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// Soma de matrizes
+impl Add<Mat2> for Mat2 {
+    type Output = Mat2;
+    fn add(self, rhs: Mat2) -> Mat2 {
+        Mat2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+/// Subtração de matrizes
+impl Sub<Mat2> for Mat2 {
+    type Output = Mat2;
+    fn sub(self, rhs: Mat2) -> Mat2 {
+        Mat2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
@@ -0,0 +1,245 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::algebra::{Isometry2, Mat2, Vec2};
+
+/// Matriz 3x3 (guardada por linhas). Em 2D é usada como transformação afim em
+/// coordenadas homogêneas: a parte 2x2 de cima é linear (rotação, escala, cisalhamento)
+/// e a última coluna é a translação.
+/// ```text
+/// | a  b  tx |
+/// | c  d  ty |
+/// | 0  0  1  |
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Mat3 {
+    pub m: [[f64; 3]; 3],
+}
+
+impl Mat3 {
+    pub const IDENTITY: Mat3 = Mat3 {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    #[inline]
+    #[must_use]
+    /// Construtor da matriz a partir das linhas
+    pub fn new(m: [[f64; 3]; 3]) -> Mat3 {
+        Mat3 { m }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Transformação afim a partir de uma parte linear e uma translação
+    pub fn from_linear(linear: Mat2, translation: Vec2) -> Mat3 {
+        Mat3::new([
+            [linear.x.x, linear.y.x, translation.x],
+            [linear.x.y, linear.y.y, translation.y],
+            [0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[inline]
+    #[must_use]
+    /// Translação
+    pub fn from_translation(translation: Vec2) -> Mat3 {
+        Mat3::from_linear(Mat2::IDENTITY, translation)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Rotação ao redor da origem (em radianos)
+    pub fn from_rotation(theta: f64) -> Mat3 {
+        let (sin, cos) = theta.sin_cos();
+        Mat3::from_linear(
+            Mat2::new(Vec2::new(cos, sin), Vec2::new(-sin, cos)),
+            Vec2::NULL,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Escala (não necessariamente uniforme) a partir da origem
+    pub fn from_scale(scale: Vec2) -> Mat3 {
+        Mat3::from_linear(Mat2::from_scale(scale), Vec2::NULL)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Cisalhamento: `x' = x + shear.x * y` e `y' = y + shear.y * x`
+    pub fn from_shear(shear: Vec2) -> Mat3 {
+        Mat3::from_linear(
+            Mat2::new(Vec2::new(1.0, shear.y), Vec2::new(shear.x, 1.0)),
+            Vec2::NULL,
+        )
+    }
+
+    #[must_use]
+    /// Transformação completa: escala, depois rotação, depois translação
+    pub fn from_scale_rotation_translation(scale: Vec2, theta: f64, translation: Vec2) -> Mat3 {
+        Mat3::from_translation(translation) * Mat3::from_rotation(theta) * Mat3::from_scale(scale)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a parte linear (2x2) da transformação
+    pub fn linear(&self) -> Mat2 {
+        Mat2::new(
+            Vec2::new(self.m[0][0], self.m[1][0]),
+            Vec2::new(self.m[0][1], self.m[1][1]),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a translação da transformação
+    pub fn translation(&self) -> Vec2 {
+        Vec2::new(self.m[0][2], self.m[1][2])
+    }
+
+    #[inline]
+    #[must_use]
+    /// Aplica a transformação em um ponto (com translação)
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.transform_vector(point) + self.translation()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Aplica a transformação em uma direção (sem translação)
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        Vec2::new(
+            self.m[0][0] * vector.x + self.m[0][1] * vector.y,
+            self.m[1][0] * vector.x + self.m[1][1] * vector.y,
+        )
+    }
+
+    #[must_use]
+    /// Aplica a transformação em uma nuvem de pontos
+    pub fn transform_points(&self, points: &[Vec2]) -> Vec<Vec2> {
+        points.iter().map(|p| self.transform_point(*p)).collect()
+    }
+
+    /// Aplica a transformação em uma nuvem de pontos no lugar
+    pub fn transform_points_mut(&self, points: &mut [Vec2]) {
+        for p in points {
+            *p = self.transform_point(*p);
+        }
+    }
+
+    #[must_use]
+    /// Retorna o determinante da matriz
+    pub fn determinant(&self) -> f64 {
+        let m = &self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    #[must_use]
+    /// Retorna a matriz transposta
+    pub fn transpose(&self) -> Mat3 {
+        let mut t = [[0.0; 3]; 3];
+        for (i, row) in self.m.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                t[j][i] = *value;
+            }
+        }
+        Mat3::new(t)
+    }
+
+    #[must_use]
+    /// Retorna a matriz inversa pela adjunta (None se a matriz for singular).
+    /// Só o determinante exatamente 0 (ou não finito) conta como singular: um limite
+    /// absoluto rejeitaria matrizes bem condicionadas com escala pequena, como
+    /// `from_scale(Vec2::new(1e-8, 1e-8))`.
+    pub fn inverse(&self) -> Option<Mat3> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let m = &self.m;
+        // Cofator (i, j) usando as linhas/colunas seguintes de forma cíclica
+        let cofactor = |i: usize, j: usize| {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+            m[i1][j1] * m[i2][j2] - m[i1][j2] * m[i2][j1]
+        };
+        let mut inverse = [[0.0; 3]; 3];
+        for (i, row) in inverse.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                // Inversa = adjunta / det (a adjunta é a transposta dos cofatores)
+                *value = cofactor(j, i) / det;
+            }
+        }
+        Some(Mat3::new(inverse))
+    }
+
+    #[must_use]
+    /// Checa se a parte linear preserva ângulos (rotação + escala uniforme, talvez espelhada)
+    pub fn is_similarity(&self) -> bool {
+        let linear = self.linear();
+        let (x, y) = (linear.x, linear.y);
+        let scale = x.length_squared().max(y.length_squared());
+        (x.length_squared() - y.length_squared()).abs() <= 1e-9 * scale
+            && x.dot(y).abs() <= 1e-9 * scale
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Mat3 {
+        Mat3::IDENTITY
+    }
+}
+
+impl From<Isometry2> for Mat3 {
+    fn from(isometry: Isometry2) -> Mat3 {
+        Mat3::from_linear(isometry.matrix(), isometry.translation)
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Produto de matrizes (aplica `rhs` primeiro)
+impl Mul<Mat3> for Mat3 {
+    type Output = Mat3;
+    fn mul(self, rhs: Mat3) -> Mat3 {
+        let mut m = [[0.0; 3]; 3];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.m[i][k] * rhs.m[k][j]).sum();
+            }
+        }
+        Mat3::new(m)
+    }
+}
+
+impl MulAssign<Mat3> for Mat3 {
+    fn mul_assign(&mut self, rhs: Mat3) {
+        *self = *self * rhs;
+    }
+}
+
+/// Multiplicação por escalar
+impl Mul<f64> for Mat3 {
+    type Output = Mat3;
+    fn mul(self, rhs: f64) -> Mat3 {
+        Mat3::new(self.m.map(|row| row.map(|value| value * rhs)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_of_small_scale() {
+        let scale = Mat3::from_scale(Vec2::new(1e-8, 1e-8));
+        let inverse = scale.inverse().expect("escala pequena não é singular");
+        let p = inverse.transform_point(Vec2::new(1e-8, -2e-8));
+        assert!((p - Vec2::new(1.0, -2.0)).length() < 1e-12);
+    }
+
+    #[test]
+    fn inverse_of_singular_matrix() {
+        assert_eq!(Mat3::from_scale(Vec2::new(0.0, 1.0)).inverse(), None);
+    }
+}
@@ -0,0 +1,193 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::algebra::{Quat, Vec3};
+
+/// Matriz 3x3 de uma transformação linear 3D (rotação, escala, cisalhamento),
+/// guardada por colunas: `x`, `y` e `z` são as imagens dos eixos X, Y e Z.
+/// É o equivalente 3D do `Mat2` (o `Mat3` é a transformação afim 2D).
+/// ```text
+/// | x.x  y.x  z.x |
+/// | x.y  y.y  z.y |
+/// | x.z  y.z  z.z |
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Mat3x3 {
+    pub x: Vec3,
+    pub y: Vec3,
+    pub z: Vec3,
+}
+
+impl Mat3x3 {
+    pub const IDENTITY: Mat3x3 = Mat3x3 {
+        x: Vec3::X,
+        y: Vec3::Y,
+        z: Vec3::Z,
+    };
+    pub const ZERO: Mat3x3 = Mat3x3 {
+        x: Vec3::NULL,
+        y: Vec3::NULL,
+        z: Vec3::NULL,
+    };
+
+    #[inline]
+    #[must_use]
+    /// Construtor da matriz a partir das colunas
+    pub fn new(x: Vec3, y: Vec3, z: Vec3) -> Mat3x3 {
+        Mat3x3 { x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construtor da matriz a partir das linhas
+    pub fn from_rows(row1: Vec3, row2: Vec3, row3: Vec3) -> Mat3x3 {
+        Mat3x3::new(
+            Vec3::new(row1.x, row2.x, row3.x),
+            Vec3::new(row1.y, row2.y, row3.y),
+            Vec3::new(row1.z, row2.z, row3.z),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Matriz de rotação equivalente ao quatérnio
+    pub fn from_rotation(rotation: Quat) -> Mat3x3 {
+        Mat3x3::new(rotation.x_axis(), rotation.y_axis(), rotation.z_axis())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Matriz de escala (diagonal)
+    pub fn from_scale(scale: Vec3) -> Mat3x3 {
+        Mat3x3::new(Vec3::X * scale.x, Vec3::Y * scale.y, Vec3::Z * scale.z)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna as linhas da matriz
+    pub fn rows(&self) -> [Vec3; 3] {
+        let t = self.transpose();
+        [t.x, t.y, t.z]
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o determinante (fator de volume da transformação)
+    pub fn determinant(&self) -> f64 {
+        self.x.dot(self.y.cross(self.z))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a matriz transposta
+    pub fn transpose(&self) -> Mat3x3 {
+        Mat3x3::from_rows(self.x, self.y, self.z)
+    }
+
+    #[must_use]
+    /// Retorna a matriz inversa (None se a matriz for singular, veja `Mat3::inverse`)
+    pub fn inverse(&self) -> Option<Mat3x3> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        // As linhas da inversa são os produtos vetoriais das colunas, divididos pelo det
+        Some(Mat3x3::from_rows(
+            self.y.cross(self.z) / det,
+            self.z.cross(self.x) / det,
+            self.x.cross(self.y) / det,
+        ))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Aplica a matriz em um vetor
+    pub fn transform(&self, v: Vec3) -> Vec3 {
+        self.x * v.x + self.y * v.y + self.z * v.z
+    }
+}
+
+impl Default for Mat3x3 {
+    fn default() -> Mat3x3 {
+        Mat3x3::IDENTITY
+    }
+}
+
+impl From<Quat> for Mat3x3 {
+    fn from(rotation: Quat) -> Mat3x3 {
+        Mat3x3::from_rotation(rotation)
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Produto de matrizes (aplica `rhs` primeiro)
+impl Mul<Mat3x3> for Mat3x3 {
+    type Output = Mat3x3;
+    fn mul(self, rhs: Mat3x3) -> Mat3x3 {
+        Mat3x3::new(
+            self.transform(rhs.x),
+            self.transform(rhs.y),
+            self.transform(rhs.z),
+        )
+    }
+}
+
+/// Matriz vezes vetor
+impl Mul<Vec3> for Mat3x3 {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.transform(rhs)
+    }
+}
+
+/// Multiplicação por escalar
+impl Mul<f64> for Mat3x3 {
+    type Output = Mat3x3;
+    fn mul(self, rhs: f64) -> Mat3x3 {
+        Mat3x3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// Soma de matrizes
+impl Add<Mat3x3> for Mat3x3 {
+    type Output = Mat3x3;
+    fn add(self, rhs: Mat3x3) -> Mat3x3 {
+        Mat3x3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+/// Subtração de matrizes
+impl Sub<Mat3x3> for Mat3x3 {
+    type Output = Mat3x3;
+    fn sub(self, rhs: Mat3x3) -> Mat3x3 {
+        Mat3x3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_matches_quaternion() {
+        let rotation = Quat::from_euler(0.3, -1.2, 2.5);
+        let matrix = Mat3x3::from(rotation);
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        assert!((matrix * v - rotation.rotate(v)).length() < 1e-12);
+
+        let back = Quat::from_mat3x3(&matrix);
+        assert!((back.rotate(v) - rotation.rotate(v)).length() < 1e-12);
+    }
+
+    #[test]
+    fn inverse_undoes_transform() {
+        let matrix = Mat3x3::new(
+            Vec3::new(2.0, 0.5, 0.0),
+            Vec3::new(-1.0, 1.0, 0.3),
+            Vec3::new(0.0, 0.2, 1e-8),
+        );
+        let inverse = matrix.inverse().expect("matriz invertível");
+        let v = Vec3::new(3.0, -1.0, 2.0);
+        assert!((inverse * (matrix * v) - v).length() < 1e-6);
+        assert_eq!(Mat3x3::from_scale(Vec3::new(1.0, 0.0, 1.0)).inverse(), None);
+    }
+}
@@ -1,3 +1,31 @@
+mod angle;
+pub mod angular;
+pub mod batch;
+mod curve;
+mod fixed;
+mod isometry2;
+mod mat2;
+mod mat3;
+mod mat3x3;
+pub mod predicates;
+mod quat;
+mod rot2;
+mod scalar;
 mod vec2;
+mod vec3;
 
-pub use vec2::Vec2;
+pub use angle::Angle;
+pub use angular::{AngularSweep, SweepEvent};
+pub use batch::Vec2Batch;
+pub use curve::{ArcLength, BSpline, CatmullRom, CubicBezier, Curve, QuadraticBezier};
+pub use fixed::Fixed;
+pub use isometry2::Isometry2;
+pub use mat2::Mat2;
+pub use mat3::Mat3;
+pub use mat3x3::Mat3x3;
+pub use predicates::Orientation;
+pub use quat::Quat;
+pub use rot2::Rot2;
+pub use scalar::Scalar;
+pub use vec2::{Vec2, Vec2f32, Vec2f64};
+pub use vec3::Vec3;
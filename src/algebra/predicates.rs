@@ -0,0 +1,293 @@
+//! Predicados geométricos robustos (no estilo de Shewchuk, "Adaptive Precision
+//! Floating-Point Arithmetic and Fast Robust Geometric Predicates").
+//!
+//! Primeiro o determinante é calculado em ponto flutuante comum junto com um limite
+//! para o erro de arredondamento. Se o resultado for maior que o erro, o sinal está
+//! certo; senão o determinante é refeito com aritmética exata (expansões: listas de
+//! `f64` sem sobreposição cuja soma é o valor exato). O sinal nunca depende de
+//! thresholds fixos, então funciona igual em coordenadas perto de 1e-6 ou de 1e6.
+
+use std::cmp::Ordering;
+
+use crate::algebra::Vec2;
+
+/// Metade do epsilon da máquina (2^-53): erro relativo máximo de uma operação
+const EPSILON: f64 = f64::EPSILON / 2.0;
+
+/// Limite relativo do erro do determinante de orientação em ponto flutuante
+const ORIENT_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+
+/// Limite relativo do erro do determinante do círculo em ponto flutuante
+const INCIRCLE_ERROR_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/// Orientação de três pontos. O sentido é o de giro do eixo X para o eixo Y
+/// (anti-horário com o Y para cima, horário na tela, onde o Y aponta para baixo).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    /// O produto vetorial `(b - a) x (c - a)` é positivo
+    CounterClockwise,
+    /// O produto vetorial `(b - a) x (c - a)` é negativo
+    Clockwise,
+    /// Os três pontos estão exatamente na mesma reta
+    Collinear,
+}
+
+impl Orientation {
+    #[inline]
+    #[must_use]
+    /// Orientação correspondente ao sinal de um determinante
+    pub fn from_sign(det: f64) -> Orientation {
+        if det > 0.0 {
+            Orientation::CounterClockwise
+        } else if det < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+}
+
+impl From<Ordering> for Orientation {
+    fn from(ordering: Ordering) -> Orientation {
+        match ordering {
+            Ordering::Greater => Orientation::CounterClockwise,
+            Ordering::Less => Orientation::Clockwise,
+            Ordering::Equal => Orientation::Collinear,
+        }
+    }
+}
+
+#[must_use]
+/// Retorna o determinante de orientação `(b - a) x (c - a)` com o sinal exato:
+/// positivo se `a, b, c` giram de X para Y, negativo no sentido oposto e zero
+/// só se os pontos forem exatamente colineares. O valor é uma aproximação.
+pub fn orient2d(a: Vec2, b: Vec2, c: Vec2) -> f64 {
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    if det.abs() > ORIENT_ERROR_BOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+    orient2d_exact(a, b, c)
+}
+
+#[must_use]
+/// Retorna a orientação exata de três pontos
+pub fn orientation(a: Vec2, b: Vec2, c: Vec2) -> Orientation {
+    Orientation::from_sign(orient2d(a, b, c))
+}
+
+#[must_use]
+/// Retorna o determinante do círculo com o sinal exato: se `a, b, c` giram de X
+/// para Y, é positivo quando `d` está dentro do círculo que passa por `a, b, c`,
+/// negativo quando está fora e zero quando está exatamente sobre ele
+/// (o sinal se inverte se `a, b, c` giram no sentido oposto). O valor é uma aproximação.
+pub fn incircle(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> f64 {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let (bc, cb) = (bd.x * cd.y, cd.x * bd.y);
+    let (ca, ac) = (cd.x * ad.y, ad.x * cd.y);
+    let (ab, ba) = (ad.x * bd.y, bd.x * ad.y);
+    let (a_lift, b_lift, c_lift) = (
+        ad.length_squared(),
+        bd.length_squared(),
+        cd.length_squared(),
+    );
+    let det = a_lift * (bc - cb) + b_lift * (ca - ac) + c_lift * (ab - ba);
+    let permanent = (bc.abs() + cb.abs()) * a_lift
+        + (ca.abs() + ac.abs()) * b_lift
+        + (ab.abs() + ba.abs()) * c_lift;
+    if det.abs() > INCIRCLE_ERROR_BOUND * permanent {
+        return det;
+    }
+    incircle_exact(a, b, c, d)
+}
+
+/// (private) Determinante de orientação com aritmética exata
+fn orient2d_exact(a: Vec2, b: Vec2, c: Vec2) -> f64 {
+    // (b - a) x (c - a) expandido em 6 produtos, cada um exato como 2 números
+    let mut det = Vec::new();
+    for (x, y, sign) in [
+        (a.x, b.y, 1.0),
+        (b.x, c.y, 1.0),
+        (c.x, a.y, 1.0),
+        (a.y, b.x, -1.0),
+        (b.y, c.x, -1.0),
+        (c.y, a.x, -1.0),
+    ] {
+        let (product, error) = two_product(x, y);
+        grow_expansion(&mut det, product * sign);
+        grow_expansion(&mut det, error * sign);
+    }
+    det.iter().sum()
+}
+
+/// (private) Determinante do círculo com aritmética exata
+fn incircle_exact(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> f64 {
+    // Diferenças exatas (2 números cada)
+    let difference = |p: f64, q: f64| {
+        let (x, y) = two_sum(p, -q);
+        let mut e = Vec::new();
+        grow_expansion(&mut e, y);
+        grow_expansion(&mut e, x);
+        e
+    };
+    let (adx, ady) = (difference(a.x, d.x), difference(a.y, d.y));
+    let (bdx, bdy) = (difference(b.x, d.x), difference(b.y, d.y));
+    let (cdx, cdy) = (difference(c.x, d.x), difference(c.y, d.y));
+
+    let lift = |x: &[f64], y: &[f64]| expansion_sum(&multiply(x, x), &multiply(y, y));
+    let cross = |x1: &[f64], y1: &[f64], x2: &[f64], y2: &[f64]| {
+        expansion_sum(&multiply(x1, y2), &negate(&multiply(y1, x2)))
+    };
+
+    let a_term = multiply(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy));
+    let b_term = multiply(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady));
+    let c_term = multiply(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy));
+    expansion_sum(&expansion_sum(&a_term, &b_term), &c_term)
+        .iter()
+        .sum()
+}
+
+/// (private) Soma exata: `a + b = x + y`, com `x` o resultado arredondado
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/// (private) Produto exato: `a * b = x + y`, com `x` o resultado arredondado
+/// (a operação fundida `mul_add` calcula o erro sem arredondar)
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+/// (private) Soma um número a uma expansão, mantendo-a sem sobreposição e em
+/// ordem crescente de magnitude (Grow-Expansion, descartando os zeros)
+fn grow_expansion(e: &mut Vec<f64>, b: f64) {
+    let mut q = b;
+    let mut out = Vec::with_capacity(e.len() + 1);
+    for component in e.iter() {
+        let (sum, error) = two_sum(q, *component);
+        if error != 0.0 {
+            out.push(error);
+        }
+        q = sum;
+    }
+    if q != 0.0 {
+        out.push(q);
+    }
+    *e = out;
+}
+
+/// (private) Soma exata de duas expansões
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut sum = e.to_vec();
+    for component in f {
+        grow_expansion(&mut sum, *component);
+    }
+    sum
+}
+
+/// (private) Produto exato de duas expansões
+fn multiply(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut product = Vec::new();
+    for a in e {
+        for b in f {
+            let (x, y) = two_product(*a, *b);
+            grow_expansion(&mut product, y);
+            grow_expansion(&mut product, x);
+        }
+    }
+    product
+}
+
+/// (private) Negativo de uma expansão
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|x| -x).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Distância entre 0.5 e o próximo `f64` (2^-53)
+    const ULP: f64 = EPSILON;
+
+    /// Determinante de orientação calculado direto em ponto flutuante
+    fn naive_orient2d(a: Vec2, b: Vec2, c: Vec2) -> f64 {
+        (a.x - c.x) * (b.y - c.y) - (a.y - c.y) * (b.x - c.x)
+    }
+
+    /// Determinante do círculo calculado direto em ponto flutuante
+    fn naive_incircle(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> f64 {
+        let (ad, bd, cd) = (a - d, b - d, c - d);
+        ad.length_squared() * bd.cross(cd)
+            + bd.length_squared() * cd.cross(ad)
+            + cd.length_squared() * ad.cross(bd)
+    }
+
+    #[test]
+    fn orientation_near_diagonal() {
+        // c fica a poucos ulps da reta y = x que passa por a e b. O sinal exato é o
+        // de `c.y - c.x`, e o cálculo direto erra vários pontos dessa grade.
+        let (a, b) = (Vec2::new(12.0, 12.0), Vec2::new(24.0, 24.0));
+        let mut naive_errors = 0;
+        for i in -32..32 {
+            for j in -32..32 {
+                let c = Vec2::new(0.5 + i as f64 * ULP, 0.5 + j as f64 * ULP);
+                let expected = Orientation::from(j.cmp(&i));
+                assert_eq!(orientation(a, b, c), expected, "c = {c:?}");
+                if Orientation::from_sign(naive_orient2d(a, b, c)) != expected {
+                    naive_errors += 1;
+                }
+            }
+        }
+        assert!(naive_errors > 0);
+    }
+
+    #[test]
+    fn incircle_near_circle() {
+        // a, b, c estão no círculo x² + y² = 0.5 (girando de X para Y) e d fica a
+        // poucos ulps de (0.5, 0.5). |d|² - 0.5 = (i + j) ulp + (i² + j²) ulp²,
+        // então com i = -j o ponto fica fora por só ~2^-106.
+        let (a, b, c) = (
+            Vec2::new(-0.5, -0.5),
+            Vec2::new(0.5, -0.5),
+            Vec2::new(-0.5, 0.5),
+        );
+        let mut naive_errors = 0;
+        for i in -32..32 {
+            for j in -32..32 {
+                let d = Vec2::new(0.5 + i as f64 * ULP, 0.5 + j as f64 * ULP);
+                let expected = if i == 0 && j == 0 {
+                    Ordering::Equal
+                } else if i + j < 0 {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                };
+                let sign = |det: f64| det.partial_cmp(&0.0).unwrap();
+                assert_eq!(sign(incircle(a, b, c, d)), expected, "d = {d:?}");
+                if sign(naive_incircle(a, b, c, d)) != expected {
+                    naive_errors += 1;
+                }
+            }
+        }
+        assert!(naive_errors > 0);
+    }
+
+    #[test]
+    fn incircle_sign_flips_with_orientation() {
+        let (a, b, c) = (
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(0.0, 4.0),
+        );
+        let inside = Vec2::new(1.0, 1.0);
+        assert!(incircle(a, b, c, inside) > 0.0);
+        assert!(incircle(a, c, b, inside) < 0.0);
+        assert_eq!(incircle(a, b, c, Vec2::new(4.0, 4.0)), 0.0);
+    }
+}
@@ -0,0 +1,262 @@
+use std::ops::{Mul, MulAssign, Neg};
+
+use crate::algebra::{Mat3x3, Vec3};
+
+/// Rotação 3D guardada como um quatérnio unitário `w + xi + yj + zk`.
+/// É o equivalente 3D do `Rot2`: compor rotações é multiplicar quatérnios,
+/// sem acumular erro em uma matriz inteira nem cair em "gimbal lock".
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Quat {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quat {
+    pub const IDENTITY: Quat = Quat {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    #[inline]
+    #[must_use]
+    /// Construtor do quatérnio a partir das componentes (não normaliza)
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Quat {
+        Quat { w, x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Rotação de `theta` radianos ao redor de um eixo (não precisa ser unitário).
+    /// O sentido é o da regra da mão direita.
+    pub fn from_axis_angle(axis: Vec3, theta: f64) -> Quat {
+        let (sin, cos) = (theta / 2.0).sin_cos();
+        let a = axis.normalized() * sin;
+        Quat::new(cos, a.x, a.y, a.z)
+    }
+
+    #[must_use]
+    /// Rotação a partir de ângulos de Euler: primeiro `roll` ao redor do X,
+    /// depois `pitch` ao redor do Y e por último `yaw` ao redor do Z
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Quat {
+        Quat::from_axis_angle(Vec3::Z, yaw)
+            * Quat::from_axis_angle(Vec3::Y, pitch)
+            * Quat::from_axis_angle(Vec3::X, roll)
+    }
+
+    #[must_use]
+    /// Menor rotação que leva a direção `from` para a direção `to`
+    pub fn from_rotation_arc(from: Vec3, to: Vec3) -> Quat {
+        let (from, to) = (from.normalized(), to.normalized());
+        let cos = from.dot(to);
+        // Direções opostas: meia volta ao redor de qualquer eixo perpendicular
+        if cos <= -1.0 + 1e-12 {
+            return Quat::from_axis_angle(from.any_perpendicular(), std::f64::consts::PI);
+        }
+        // q = (1 + cos, from x to) normalizado tem metade do ângulo entre os vetores
+        let axis = from.cross(to);
+        Quat::new(1.0 + cos, axis.x, axis.y, axis.z).normalized()
+    }
+
+    #[must_use]
+    /// Converte uma matriz de rotação (ortonormal, determinante 1) para quatérnio
+    /// (método de Shepperd: escolhe a maior componente para não dividir por ~0)
+    pub fn from_mat3x3(matrix: &Mat3x3) -> Quat {
+        let m = matrix.rows().map(|row| [row.x, row.y, row.z]);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quat::new(
+                s / 4.0,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+            Quat::new(
+                (m[2][1] - m[1][2]) / s,
+                s / 4.0,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+            Quat::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / 4.0,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+            Quat::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / 4.0,
+            )
+        };
+        q.normalized()
+    }
+
+    #[must_use]
+    /// Retorna o eixo (unitário) e o ângulo da rotação, em `[0, π]`
+    pub fn axis_angle(self) -> (Vec3, f64) {
+        // q e -q são a mesma rotação: usa o que tem w >= 0 para o menor ângulo
+        let q = if self.w < 0.0 { -self } else { self };
+        let v = Vec3::new(q.x, q.y, q.z);
+        let sin = v.length();
+        if sin <= 1e-12 {
+            return (Vec3::X, 0.0);
+        }
+        (v / sin, 2.0 * sin.atan2(q.w))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o produto escalar entre dois quatérnios
+    pub fn dot(self, other: Quat) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o tamanho do quatérnio (1 para rotações)
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Corrige o tamanho do quatérnio para 1 (erro numérico acumulado)
+    pub fn normalized(self) -> Quat {
+        let length = self.length();
+        if length <= 0.0 {
+            return Quat::IDENTITY;
+        }
+        Quat::new(
+            self.w / length,
+            self.x / length,
+            self.y / length,
+            self.z / length,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a rotação inversa (o conjugado, já que o quatérnio é unitário)
+    pub fn inverse(self) -> Quat {
+        Quat::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Rotaciona um vetor (`q v q*`, expandido para não montar quatérnios intermediários)
+    pub fn rotate(self, v: Vec3) -> Vec3 {
+        let u = Vec3::new(self.x, self.y, self.z);
+        let t = u.cross(v) * 2.0;
+        v + t * self.w + u.cross(t)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Aplica a rotação inversa em um vetor
+    pub fn inverse_rotate(self, v: Vec3) -> Vec3 {
+        self.inverse().rotate(v)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o eixo X rotacionado
+    pub fn x_axis(self) -> Vec3 {
+        self.rotate(Vec3::X)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o eixo Y rotacionado
+    pub fn y_axis(self) -> Vec3 {
+        self.rotate(Vec3::Y)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o eixo Z rotacionado
+    pub fn z_axis(self) -> Vec3 {
+        self.rotate(Vec3::Z)
+    }
+
+    #[must_use]
+    /// Interpolação pelo menor arco com velocidade angular constante
+    pub fn slerp(self, other: Quat, t: f64) -> Quat {
+        // q e -q são a mesma rotação: escolhe o sinal que dá o caminho curto
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            -other
+        } else {
+            other
+        };
+        // Quase iguais: interpolação linear (evita dividir por sin ~ 0)
+        let (a, b) = if cos > 1.0 - 1e-9 {
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        Quat::new(
+            self.w * a + other.w * b,
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+        )
+        .normalized()
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Quat {
+        Quat::IDENTITY
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Composição de rotações (aplica `rhs` primeiro)
+impl Mul<Quat> for Quat {
+    type Output = Quat;
+    fn mul(self, rhs: Quat) -> Quat {
+        Quat::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl MulAssign<Quat> for Quat {
+    fn mul_assign(&mut self, rhs: Quat) {
+        *self = *self * rhs;
+    }
+}
+
+/// Rotação de um vetor
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.rotate(rhs)
+    }
+}
+
+/// Negativo de todas as componentes (representa a mesma rotação)
+impl Neg for Quat {
+    type Output = Quat;
+    fn neg(self) -> Quat {
+        Quat::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
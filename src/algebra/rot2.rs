@@ -0,0 +1,141 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::algebra::Vec2;
+
+/// Rotação 2D guardada como o par (cos, sin) do ângulo (um número complexo unitário).
+/// Compor rotações é só multiplicar os pares, sem chamar `sin`/`cos` de novo.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rot2 {
+    pub cos: f64,
+    pub sin: f64,
+}
+
+impl Rot2 {
+    pub const IDENTITY: Rot2 = Rot2 { cos: 1.0, sin: 0.0 };
+
+    #[inline]
+    #[must_use]
+    /// Construtor da rotação a partir de um ângulo em radianos
+    pub fn new(theta: f64) -> Rot2 {
+        let (sin, cos) = theta.sin_cos();
+        Rot2 { cos, sin }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Rotação que leva o eixo X para a direção de um vetor (não precisa ser unitário).
+    /// O vetor nulo não tem direção: retorna `Rot2::IDENTITY`, como `normalized`
+    pub fn from_vector(direction: Vec2) -> Rot2 {
+        Rot2 {
+            cos: direction.x,
+            sin: direction.y,
+        }
+        .normalized()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o ângulo da rotação em (-π, π]
+    pub fn angle(self) -> f64 {
+        self.sin.atan2(self.cos)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a rotação inversa (ângulo negativo)
+    pub fn inverse(self) -> Rot2 {
+        Rot2 {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Corrige o tamanho do par (cos, sin) para 1 (erro numérico acumulado)
+    pub fn normalized(self) -> Rot2 {
+        let length = (self.cos * self.cos + self.sin * self.sin).sqrt();
+        if length <= 0.0 {
+            return Rot2::IDENTITY;
+        }
+        Rot2 {
+            cos: self.cos / length,
+            sin: self.sin / length,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o eixo X rotacionado
+    pub fn x_axis(self) -> Vec2 {
+        Vec2::new(self.cos, self.sin)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o eixo Y rotacionado (sempre perpendicular ao eixo X)
+    pub fn y_axis(self) -> Vec2 {
+        Vec2::new(-self.sin, self.cos)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Rotaciona um vetor
+    pub fn rotate(self, v: Vec2) -> Vec2 {
+        Vec2::new(
+            v.x * self.cos - v.y * self.sin,
+            v.x * self.sin + v.y * self.cos,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Aplica a rotação inversa em um vetor
+    pub fn inverse_rotate(self, v: Vec2) -> Vec2 {
+        self.inverse().rotate(v)
+    }
+}
+
+impl Default for Rot2 {
+    fn default() -> Rot2 {
+        Rot2::IDENTITY
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Composição de rotações (soma dos ângulos)
+impl Mul<Rot2> for Rot2 {
+    type Output = Rot2;
+    fn mul(self, rhs: Rot2) -> Rot2 {
+        Rot2 {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+
+impl MulAssign<Rot2> for Rot2 {
+    fn mul_assign(&mut self, rhs: Rot2) {
+        *self = *self * rhs;
+    }
+}
+
+/// Rotação de um vetor
+impl Mul<Vec2> for Rot2 {
+    type Output = Vec2;
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        self.rotate(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_vector_of_zero_is_identity() {
+        assert_eq!(Rot2::from_vector(Vec2::NULL), Rot2::IDENTITY);
+        let rotation = Rot2::from_vector(Vec2::new(0.0, 3.0));
+        assert!((rotation.angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::algebra::Vec2;
+use crate::algebra::predicates::{self, Orientation};
+
+/// Trait para os números usados pelos vetores e formas (f32 e f64).
+/// Junta as operações aritméticas e as funções matemáticas que a biblioteca usa,
+/// assim `Vec2<T>` e as formas funcionam com qualquer precisão.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Display
+    + Default
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// Maior valor representável (infinito nos tipos de ponto flutuante)
+    const INFINITY: Self;
+    /// Diferença entre 1 e o próximo valor representável
+    const EPSILON: Self;
+
+    /// Converte de f64 (literais e constantes)
+    fn from_f64(value: f64) -> Self;
+    /// Converte para f64
+    fn to_f64(self) -> f64;
+    /// Converte para f32 (para desenhar com a Macroquad)
+    fn to_f32(self) -> f32;
+
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    /// Maior inteiro menor ou igual ao valor
+    fn floor(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    /// Comparação total (ordena NaN junto dos outros valores)
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    #[inline]
+    #[must_use]
+    /// Limita o valor ao intervalo `[min, max]`
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna -1, 0 ou 1 de acordo com o sinal do valor
+    fn signum(self) -> Self {
+        if self > Self::ZERO {
+            Self::ONE
+        } else if self < Self::ZERO {
+            -Self::ONE
+        } else {
+            Self::ZERO
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Orientação exata de três pontos (veja `predicates::orient2d`).
+    /// Por padrão converte para f64, o que é exato para f32 e f64.
+    fn orientation(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>) -> Orientation {
+        let to_f64 = |p: Vec2<Self>| Vec2::new(p.x.to_f64(), p.y.to_f64());
+        predicates::orientation(to_f64(a), to_f64(b), to_f64(c))
+    }
+}
+
+/// Implementa `Scalar` para um tipo de ponto flutuante da biblioteca padrão
+macro_rules! impl_scalar_float {
+    ($t:ident) => {
+        impl Scalar for $t {
+            const ZERO: $t = 0.0;
+            const ONE: $t = 1.0;
+            const INFINITY: $t = $t::INFINITY;
+            const EPSILON: $t = $t::EPSILON;
+
+            #[inline]
+            fn from_f64(value: f64) -> $t {
+                value as $t
+            }
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            #[inline]
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+            #[inline]
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+            #[inline]
+            fn floor(self) -> $t {
+                $t::floor(self)
+            }
+            #[inline]
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+            #[inline]
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+            #[inline]
+            fn asin(self) -> $t {
+                $t::asin(self)
+            }
+            #[inline]
+            fn acos(self) -> $t {
+                $t::acos(self)
+            }
+            #[inline]
+            fn atan2(self, x: $t) -> $t {
+                $t::atan2(self, x)
+            }
+            #[inline]
+            fn min(self, other: $t) -> $t {
+                $t::min(self, other)
+            }
+            #[inline]
+            fn max(self, other: $t) -> $t {
+                $t::max(self, other)
+            }
+            #[inline]
+            fn total_cmp(&self, other: &$t) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+            #[inline]
+            fn clamp(self, min: $t, max: $t) -> $t {
+                $t::clamp(self, min, max)
+            }
+        }
+    };
+}
+
+impl_scalar_float!(f32);
+impl_scalar_float!(f64);
@@ -5,87 +5,104 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssi
 use macroquad::color::Color;
 use macroquad::shapes::draw_circle;
 
+use crate::algebra::{Fixed, Scalar};
+
+/// Vetor 2D genérico no tipo dos números (f64 por padrão)
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
-pub struct Vec2 {
-    pub x: f64,
-    pub y: f64,
+pub struct Vec2<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
+/// Vetor de precisão simples
+pub type Vec2f32 = Vec2<f32>;
+/// Vetor de precisão dupla
+pub type Vec2f64 = Vec2<f64>;
+
 /// Epsilon para erro numérico
 const EPS: f64 = 1e-6;
 const EPS_SQR: f64 = EPS * EPS;
 
 // Métodos
-impl Vec2 {
-    pub const NULL: Vec2 = Vec2 { x: 0.0, y: 0.0 };
-    pub const X: Vec2 = Vec2 { x: 1.0, y: 0.0 };
-    pub const Y: Vec2 = Vec2 { x: 0.0, y: 1.0 };
+impl<T: Scalar> Vec2<T> {
+    pub const NULL: Vec2<T> = Vec2 {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+    pub const X: Vec2<T> = Vec2 {
+        x: T::ONE,
+        y: T::ZERO,
+    };
+    pub const Y: Vec2<T> = Vec2 {
+        x: T::ZERO,
+        y: T::ONE,
+    };
 
     #[inline]
     #[must_use]
     /// Construtor do Vec2.
-    pub fn new(x: f64, y: f64) -> Vec2 {
+    pub fn new(x: T, y: T) -> Vec2<T> {
         return Vec2 { x, y };
     }
 
     #[inline]
     #[must_use]
     /// Retorna o vetor unitário alinhado ao eixo X rotacionado em theta radianos
-    pub fn from_angle(theta: f64) -> Vec2 {
+    pub fn from_angle(theta: T) -> Vec2<T> {
         return Vec2::new(theta.cos(), theta.sin());
     }
 
     #[inline]
     pub fn draw(&self, color: Color) {
-        draw_circle(self.x as f32, self.y as f32, 3.0, color);
+        draw_circle(self.x.to_f32(), self.y.to_f32(), 3.0, color);
     }
 
     #[inline]
     #[must_use]
     /// Retorna o produto escalar entre dois vetores
-    pub fn dot(self, rhs: Vec2) -> f64 {
+    pub fn dot(self, rhs: Vec2<T>) -> T {
         return self.x * rhs.x + self.y * rhs.y;
     }
 
     #[inline]
     #[must_use]
     /// Retorna o produto vetorial entre dois vetores
-    pub fn cross(self, rhs: Vec2) -> f64 {
+    pub fn cross(self, rhs: Vec2<T>) -> T {
         return self.x * rhs.y - self.y * rhs.x;
     }
 
     #[inline]
     #[must_use]
     /// Retorna o quadrado do tamanho do vetor (mais rápido que length() * length())
-    pub fn length_squared(self) -> f64 {
+    pub fn length_squared(self) -> T {
         return self.x * self.x + self.y * self.y;
     }
 
     #[inline]
     #[must_use]
     /// Retorna o tamanho do vetor
-    pub fn length(self) -> f64 {
+    pub fn length(self) -> T {
         return self.length_squared().sqrt();
     }
 
     #[inline]
     #[must_use]
     /// Retorna a distância ao quadrado de um ponto até o outro
-    pub fn distance_to_squared(self, other: Vec2) -> f64 {
+    pub fn distance_to_squared(self, other: Vec2<T>) -> T {
         (self - other).length_squared()
     }
 
     #[inline]
     #[must_use]
     /// Retorna a distância de um ponto até o outro
-    pub fn distance_to(self, other: Vec2) -> f64 {
+    pub fn distance_to(self, other: Vec2<T>) -> T {
         (self - other).length()
     }
 
     #[inline]
     #[must_use]
     /// Retorna o vetor normalizado (divide o vetor pelo seu tamanho)
-    pub fn normalized(self) -> Vec2 {
+    pub fn normalized(self) -> Vec2<T> {
         return self / self.length();
     }
 
@@ -93,53 +110,61 @@ impl Vec2 {
     #[must_use]
     /// Checa se o tamanho do vetor é 1 (threshold: `1e-6` aplicado ao comprimento ao quadrado -> `1e-12`)
     pub fn is_normalized(self) -> bool {
-        return (self.length_squared() - 1.0).abs() <= EPS_SQR;
+        return (self.length_squared() - T::ONE).abs() <= T::from_f64(EPS_SQR);
     }
 
     #[inline]
     #[must_use]
-    /// Checa se o vetor é paralelo ao outro (threshold: `1e-6`)
-    pub fn is_parallel(self, other: Vec2) -> bool {
-        return self.cross(other).abs() <= EPS;
+    /// Checa se o vetor é paralelo ao outro (threshold: `1e-6` no seno do ângulo
+    /// entre eles, então não depende do tamanho dos vetores)
+    pub fn is_parallel(self, other: Vec2<T>) -> bool {
+        let scale = self.length() * other.length();
+        return self.cross(other).abs() <= T::from_f64(EPS) * scale;
     }
 
     #[inline]
     #[must_use]
     /// Checa se o vetor é igual ao outro com threshold: `1e-6`
     /// (aplicado ao comprimento ao quadrado -> `1e-12`)
-    pub fn is_same(self, other: Vec2) -> bool {
-        return self.distance_to_squared(other) <= EPS_SQR;
+    pub fn is_same(self, other: Vec2<T>) -> bool {
+        return self.distance_to_squared(other) <= T::from_f64(EPS_SQR);
     }
 
     #[inline]
     #[must_use]
     /// Usa a definição do produto escalar para calcular o ângulo entre dois vetores
-    pub fn angle_between(self, other: Vec2) -> f64 {
-        let cos_theta = self.normalized().dot(other.normalized()).clamp(-1.0, 1.0);
+    pub fn angle_between(self, other: Vec2<T>) -> T {
+        let cos_theta = self
+            .normalized()
+            .dot(other.normalized())
+            .clamp(-T::ONE, T::ONE);
         return cos_theta.acos();
     }
 
     #[inline]
     #[must_use]
     /// Usa a definição do produto vetorial para calcular o ângulo entre dois vetores
-    pub fn angle_between_cross(self, other: Vec2) -> f64 {
-        let sin_theta = self.normalized().cross(other.normalized()).clamp(-1.0, 1.0);
+    pub fn angle_between_cross(self, other: Vec2<T>) -> T {
+        let sin_theta = self
+            .normalized()
+            .cross(other.normalized())
+            .clamp(-T::ONE, T::ONE);
         return sin_theta.asin();
     }
 
     #[inline]
     #[must_use]
     /// Usa a fórmula `1 - (u.v / ||u||||v||)` para retornar o pseudoângulo do cosseno
-    pub fn cos_pseudoangle_between(self, other: Vec2) -> f64 {
+    pub fn cos_pseudoangle_between(self, other: Vec2<T>) -> T {
         let top = self.dot(other);
         let bottom = self.length() * other.length();
-        return 1.0 - (top / bottom).clamp(-1.0, 1.0);
+        return T::ONE - (top / bottom).clamp(-T::ONE, T::ONE);
     }
 
     #[inline]
     #[must_use]
     /// Retorna o pseudoângulo em `[0,8)` do vetor no perímetro do quadrado
-    pub fn square_pseudoangle(self) -> f64 {
+    pub fn square_pseudoangle(self) -> T {
         let x = self.x;
         let y = self.y;
 
@@ -147,44 +172,44 @@ impl Vec2 {
         let ax = x.abs();
         let ay = y.abs();
 
-        if x >= 0.0 {
-            if y >= 0.0 {
+        if x >= T::ZERO {
+            if y >= T::ZERO {
                 // Octantes 1 e 2
                 if ax >= ay {
                     // 1
                     ay / ax
                 } else {
                     // 2 -> px é "excesso" de (0,1)
-                    2.0 - ax / ay
+                    T::from_f64(2.0) - ax / ay
                 }
             } else {
                 // Octantes 7 e 8
                 if ax >= ay {
                     // 8 -> px é "excesso" de (1,0)
-                    8.0 - ay / ax
+                    T::from_f64(8.0) - ay / ax
                 } else {
                     // 7 -> ponto (0,-1) vale 6.0, soma px
-                    6.0 + ax / ay
+                    T::from_f64(6.0) + ax / ay
                 }
             }
         } else {
-            if y >= 0.0 {
+            if y >= T::ZERO {
                 // Octantes 3 e 4
                 if ax >= ay {
                     // 4 -> px é "excesso" de (-1,0)
-                    4.0 - ay / ax
+                    T::from_f64(4.0) - ay / ax
                 } else {
                     // 3 -> ponto (0,1) vale 2.0, soma px
-                    2.0 + ax / ay
+                    T::from_f64(2.0) + ax / ay
                 }
             } else {
                 // Octantes 5 e 6
                 if ax >= ay {
                     // 5 -> ponto (-1,0) vale 4.0, soma px
-                    4.0 + ay / ax
+                    T::from_f64(4.0) + ay / ax
                 } else {
                     // 6 -> px é "excesso" de (0,-1)
-                    6.0 - ax / ay
+                    T::from_f64(6.0) - ax / ay
                 }
             }
         }
@@ -193,14 +218,14 @@ impl Vec2 {
     #[inline]
     #[must_use]
     /// Retorna o pseudoângulo entre dois vetores em `[0,8)`
-    pub fn square_pseudoangle_between(self, other: Vec2) -> f64 {
+    pub fn square_pseudoangle_between(self, other: Vec2<T>) -> T {
         let a = self.square_pseudoangle();
         let b = other.square_pseudoangle();
 
         let pseudo = b - a;
-        if pseudo < 0.0 {
+        if pseudo < T::ZERO {
             // mantém o range em [0,8)
-            pseudo + 8.0
+            pseudo + T::from_f64(8.0)
         } else {
             pseudo
         }
@@ -210,7 +235,7 @@ impl Vec2 {
     #[must_use]
     /// Gira o vetor em um ângulo específico ao redor da origem.
     /// Isso usa a definição da matriz de rotação para os cálculos.
-    pub fn rotated(self, theta: f64) -> Vec2 {
+    pub fn rotated(self, theta: T) -> Vec2<T> {
         let new_x = self.x * theta.cos() - self.y * theta.sin();
         let new_y = self.x * theta.sin() + self.y * theta.cos();
         return Vec2 { x: new_x, y: new_y };
@@ -219,16 +244,16 @@ impl Vec2 {
     #[inline]
     #[must_use]
     /// Reflete o vetor em torno de um vetor normal
-    pub fn bounce(self, normal: Vec2) -> Vec2 {
-        self - 2.0 * self.dot(normal) * normal
+    pub fn bounce(self, normal: Vec2<T>) -> Vec2<T> {
+        self - normal * (T::from_f64(2.0) * self.dot(normal))
     }
 }
 
 ////// OPERATOR OVERLOADS //////
 /// Adição de vetores
-impl Add<Vec2> for Vec2 {
-    type Output = Vec2;
-    fn add(self, rhs: Vec2) -> Vec2 {
+impl<T: Scalar> Add<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
+    fn add(self, rhs: Vec2<T>) -> Vec2<T> {
         return Vec2 {
             x: self.x + rhs.x,
             y: self.y + rhs.y,
@@ -236,17 +261,17 @@ impl Add<Vec2> for Vec2 {
     }
 }
 
-impl AddAssign<Vec2> for Vec2 {
-    fn add_assign(&mut self, rhs: Vec2) {
+impl<T: Scalar> AddAssign<Vec2<T>> for Vec2<T> {
+    fn add_assign(&mut self, rhs: Vec2<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
 /// Subtração de vetores
-impl Sub<Vec2> for Vec2 {
-    type Output = Vec2;
-    fn sub(self, rhs: Vec2) -> Vec2 {
+impl<T: Scalar> Sub<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
+    fn sub(self, rhs: Vec2<T>) -> Vec2<T> {
         return Vec2 {
             x: self.x - rhs.x,
             y: self.y - rhs.y,
@@ -254,17 +279,17 @@ impl Sub<Vec2> for Vec2 {
     }
 }
 
-impl SubAssign<Vec2> for Vec2 {
-    fn sub_assign(&mut self, rhs: Vec2) {
+impl<T: Scalar> SubAssign<Vec2<T>> for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
 
 /// Divisão por escalar
-impl Div<f64> for Vec2 {
-    type Output = Vec2;
-    fn div(self, rhs: f64) -> Vec2 {
+impl<T: Scalar> Div<T> for Vec2<T> {
+    type Output = Vec2<T>;
+    fn div(self, rhs: T) -> Vec2<T> {
         return Vec2 {
             x: self.x / rhs,
             y: self.y / rhs,
@@ -272,17 +297,17 @@ impl Div<f64> for Vec2 {
     }
 }
 
-impl DivAssign<f64> for Vec2 {
-    fn div_assign(&mut self, rhs: f64) {
+impl<T: Scalar> DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self, rhs: T) {
         self.x /= rhs;
         self.y /= rhs;
     }
 }
 
 /// Multiplicação por escalar
-impl Mul<f64> for Vec2 {
-    type Output = Vec2;
-    fn mul(self, rhs: f64) -> Vec2 {
+impl<T: Scalar> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+    fn mul(self, rhs: T) -> Vec2<T> {
         return Vec2 {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -290,27 +315,36 @@ impl Mul<f64> for Vec2 {
     }
 }
 
-impl Mul<Vec2> for f64 {
-    type Output = Vec2;
-    fn mul(self, rhs: Vec2) -> Vec2 {
-        return Vec2 {
-            x: self * rhs.x,
-            y: self * rhs.y,
-        };
-    }
+/// Escalar vezes vetor (implementado para cada tipo, já que o escalar fica à esquerda)
+macro_rules! impl_scalar_mul_vec2 {
+    ($t:ty) => {
+        impl Mul<Vec2<$t>> for $t {
+            type Output = Vec2<$t>;
+            fn mul(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                return Vec2 {
+                    x: self * rhs.x,
+                    y: self * rhs.y,
+                };
+            }
+        }
+    };
 }
 
-impl MulAssign<f64> for Vec2 {
-    fn mul_assign(&mut self, rhs: f64) {
+impl_scalar_mul_vec2!(f32);
+impl_scalar_mul_vec2!(f64);
+impl_scalar_mul_vec2!(Fixed);
+
+impl<T: Scalar> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
     }
 }
 
 /// Negativo do vetor
-impl Neg for Vec2 {
-    type Output = Vec2;
-    fn neg(self) -> Vec2 {
+impl<T: Scalar> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+    fn neg(self) -> Vec2<T> {
         return Vec2 {
             x: -self.x,
             y: -self.y,
@@ -318,7 +352,7 @@ impl Neg for Vec2 {
     }
 }
 
-impl Sum for Vec2 {
+impl<T: Scalar> Sum for Vec2<T> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(Vec2::NULL, |a, b| Vec2 {
             x: a.x + b.x,
@@ -327,9 +361,31 @@ impl Sum for Vec2 {
     }
 }
 
+/// Conversão para o vetor da Macroquad (f32), para desenhar sem `as f32`
+impl<T: Scalar> From<Vec2<T>> for macroquad::math::Vec2 {
+    fn from(v: Vec2<T>) -> macroquad::math::Vec2 {
+        macroquad::math::Vec2::new(v.x.to_f32(), v.y.to_f32())
+    }
+}
+
 /// Implementa o trait "Display" pra printar bonitinho etc.
-impl Display for Vec2 {
+impl<T: Scalar> Display for Vec2<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_parallel_at_any_scale() {
+        // Seno do ângulo 1e-8 (abaixo do threshold) ou 1 (perpendiculares)
+        for scale in [1e-100, 1.0, 1e100] {
+            let x = Vec2::new(scale, 0.0);
+            assert!(x.is_parallel(Vec2::new(scale, scale * 1e-8)));
+            assert!(!x.is_parallel(Vec2::new(0.0, scale)));
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::algebra::{Fixed, Scalar, Vec2};
+
+/// Vetor 3D genérico no tipo dos números (f64 por padrão)
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Vec3<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Epsilon para erro numérico
+const EPS: f64 = 1e-6;
+const EPS_SQR: f64 = EPS * EPS;
+
+// Métodos
+impl<T: Scalar> Vec3<T> {
+    pub const NULL: Vec3<T> = Vec3 {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+    pub const X: Vec3<T> = Vec3 {
+        x: T::ONE,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+    pub const Y: Vec3<T> = Vec3 {
+        x: T::ZERO,
+        y: T::ONE,
+        z: T::ZERO,
+    };
+    pub const Z: Vec3<T> = Vec3 {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ONE,
+    };
+
+    #[inline]
+    #[must_use]
+    /// Construtor do Vec3.
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Constrói o vetor a partir de um `Vec2` (plano XY) e da altura `z`
+    pub fn from_vec2(v: Vec2<T>, z: T) -> Vec3<T> {
+        Vec3::new(v.x, v.y, z)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a projeção do vetor no plano XY (descarta o `z`)
+    pub fn xy(self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o produto escalar entre dois vetores
+    pub fn dot(self, rhs: Vec3<T>) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o produto vetorial entre dois vetores (perpendicular aos dois,
+    /// pela regra da mão direita)
+    pub fn cross(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o quadrado do tamanho do vetor (mais rápido que length() * length())
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o tamanho do vetor
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a distância ao quadrado de um ponto até o outro
+    pub fn distance_to_squared(self, other: Vec3<T>) -> T {
+        (self - other).length_squared()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a distância de um ponto até o outro
+    pub fn distance_to(self, other: Vec3<T>) -> T {
+        (self - other).length()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o vetor normalizado (divide o vetor pelo seu tamanho)
+    pub fn normalized(self) -> Vec3<T> {
+        self / self.length()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se o tamanho do vetor é 1 (threshold: `1e-6` aplicado ao comprimento ao quadrado -> `1e-12`)
+    pub fn is_normalized(self) -> bool {
+        (self.length_squared() - T::ONE).abs() <= T::from_f64(EPS_SQR)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se o vetor é paralelo ao outro (threshold: `1e-6` no seno do ângulo
+    /// entre eles, então não depende do tamanho dos vetores)
+    pub fn is_parallel(self, other: Vec3<T>) -> bool {
+        let scale = self.length() * other.length();
+        self.cross(other).length() <= T::from_f64(EPS) * scale
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se o vetor é igual ao outro com threshold: `1e-6`
+    /// (aplicado ao comprimento ao quadrado -> `1e-12`)
+    pub fn is_same(self, other: Vec3<T>) -> bool {
+        self.distance_to_squared(other) <= T::from_f64(EPS_SQR)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Usa a definição do produto escalar para calcular o ângulo entre dois vetores
+    pub fn angle_between(self, other: Vec3<T>) -> T {
+        let cos_theta = self
+            .normalized()
+            .dot(other.normalized())
+            .clamp(-T::ONE, T::ONE);
+        cos_theta.acos()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o vetor com o valor absoluto de cada componente
+    pub fn abs(self) -> Vec3<T> {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o mínimo de cada componente
+    pub fn min(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o máximo de cada componente
+    pub fn max(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Produto componente a componente
+    pub fn scale(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x * other.x, self.y * other.y, self.z * other.z)
+    }
+
+    #[must_use]
+    /// Retorna um vetor unitário qualquer perpendicular a este
+    /// (usa o eixo em que o vetor é menor, para não degenerar)
+    pub fn any_perpendicular(self) -> Vec3<T> {
+        let a = self.abs();
+        let axis = if a.x <= a.y && a.x <= a.z {
+            Vec3::X
+        } else if a.y <= a.z {
+            Vec3::Y
+        } else {
+            Vec3::Z
+        };
+        self.cross(axis).normalized()
+    }
+
+    #[must_use]
+    /// Gira o vetor em `theta` radianos ao redor de um eixo unitário que passa pela
+    /// origem (fórmula de Rodrigues; o sentido é o da regra da mão direita)
+    pub fn rotated(self, axis: Vec3<T>, theta: T) -> Vec3<T> {
+        let (sin, cos) = (theta.sin(), theta.cos());
+        self * cos + axis.cross(self) * sin + axis * (axis.dot(self) * (T::ONE - cos))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Reflete o vetor em torno de um vetor normal
+    pub fn bounce(self, normal: Vec3<T>) -> Vec3<T> {
+        self - normal * (T::from_f64(2.0) * self.dot(normal))
+    }
+}
+
+////// OPERATOR OVERLOADS //////
+/// Adição de vetores
+impl<T: Scalar> Add<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn add(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Scalar> AddAssign<Vec3<T>> for Vec3<T> {
+    fn add_assign(&mut self, rhs: Vec3<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+/// Subtração de vetores
+impl<T: Scalar> Sub<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn sub(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Scalar> SubAssign<Vec3<T>> for Vec3<T> {
+    fn sub_assign(&mut self, rhs: Vec3<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// Divisão por escalar
+impl<T: Scalar> Div<T> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn div(self, rhs: T) -> Vec3<T> {
+        Vec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Vec3<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+/// Multiplicação por escalar
+impl<T: Scalar> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn mul(self, rhs: T) -> Vec3<T> {
+        Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// Escalar vezes vetor (implementado para cada tipo, já que o escalar fica à esquerda)
+macro_rules! impl_scalar_mul_vec3 {
+    ($t:ty) => {
+        impl Mul<Vec3<$t>> for $t {
+            type Output = Vec3<$t>;
+            fn mul(self, rhs: Vec3<$t>) -> Vec3<$t> {
+                rhs * self
+            }
+        }
+    };
+}
+
+impl_scalar_mul_vec3!(f32);
+impl_scalar_mul_vec3!(f64);
+impl_scalar_mul_vec3!(Fixed);
+
+impl<T: Scalar> MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+/// Negativo do vetor
+impl<T: Scalar> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+    fn neg(self) -> Vec3<T> {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Scalar> Sum for Vec3<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec3::NULL, |a, b| a + b)
+    }
+}
+
+/// Conversão para o vetor da Macroquad (f32), para desenhar sem `as f32`
+impl<T: Scalar> From<Vec3<T>> for macroquad::math::Vec3 {
+    fn from(v: Vec3<T>) -> macroquad::math::Vec3 {
+        macroquad::math::Vec3::new(v.x.to_f32(), v.y.to_f32(), v.z.to_f32())
+    }
+}
+
+/// Implementa o trait "Display" pra printar bonitinho etc.
+impl<T: Scalar> Display for Vec3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_parallel_at_any_scale() {
+        // Seno do ângulo 1e-8 (abaixo do threshold) ou 1 (perpendiculares).
+        // Em 1e80 o produto dos comprimentos ao quadrado já estoura para infinito.
+        for scale in [1e-80, 1.0, 1e80] {
+            let x = Vec3::new(scale, 0.0, 0.0);
+            assert!(x.is_parallel(Vec3::new(scale, 0.0, scale * 1e-8)));
+            assert!(!x.is_parallel(Vec3::new(0.0, scale, 0.0)));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use macroquad::color::Color;
+
+use crate::{algebra::Vec2, physics::shapes::Collider};
+
+/// Corpo rígido com um collider como forma (AABB, OBB, Circle, Polygon...).
+/// O collider guarda a posição e a orientação; o corpo guarda as velocidades e a massa.
+/// (AABBs e círculos não giram visualmente, mas a velocidade angular ainda é integrada.)
+pub struct RigidBody<C: Collider> {
+    pub collider: C,
+    pub velocity: Vec2,
+    /// Velocidade angular em radianos por segundo
+    pub angular_velocity: f64,
+    pub mass: f64,
+    /// Momento de inércia
+    pub inertia: f64,
+}
+
+impl<C: Collider> RigidBody<C> {
+    #[must_use]
+    /// Construtor do corpo. O momento de inércia é o de um retângulo do tamanho do
+    /// collider, ou o de um disco se o collider não tiver bordas (círculo).
+    pub fn new(collider: C, mass: f64) -> RigidBody<C> {
+        let size = collider.size();
+        let inertia = if collider.edges().is_empty() {
+            // Círculo: size() = (raio, raio)
+            mass * size.x * size.x / 2.0
+        } else {
+            mass * size.length_squared() / 12.0
+        };
+        RigidBody {
+            collider,
+            velocity: Vec2::NULL,
+            angular_velocity: 0.0,
+            mass,
+            inertia,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a posição (centro) do corpo
+    pub fn position(&self) -> Vec2 {
+        self.collider.center()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a velocidade de um ponto do corpo: `v + ω × r`
+    pub fn velocity_at(&self, point: Vec2) -> Vec2 {
+        let r = point - self.position();
+        self.velocity + Vec2::new(-r.y, r.x) * self.angular_velocity
+    }
+
+    /// Aplica um impulso em um ponto do corpo (muda as velocidades linear e angular)
+    pub fn apply_impulse(&mut self, impulse: Vec2, point: Vec2) {
+        let r = point - self.position();
+        self.velocity += impulse / self.mass;
+        self.angular_velocity += r.cross(impulse) / self.inertia;
+    }
+
+    #[inline]
+    /// Aplica uma força em um ponto do corpo durante `delta` segundos
+    pub fn apply_force(&mut self, force: Vec2, point: Vec2, delta: f64) {
+        self.apply_impulse(force * delta, point);
+    }
+
+    /// Move e gira o collider de acordo com as velocidades (Euler semi-implícito)
+    pub fn integrate(&mut self, delta: f64, gravity: Vec2) {
+        self.velocity += gravity * delta;
+        let position = self.position() + self.velocity * delta;
+        self.collider.set_center(position);
+        self.collider.rotate(self.angular_velocity * delta);
+    }
+
+    #[inline]
+    /// Desenha o collider do corpo
+    pub fn draw(&self, thickness: f32, color: Color) {
+        self.collider.draw(thickness, color);
+    }
+}
@@ -0,0 +1,178 @@
+use macroquad::color::Color;
+
+use crate::{
+    algebra::Vec2,
+    physics::{
+        body::RigidBody,
+        shapes::{AABB, Collider, Polygon},
+    },
+};
+
+/// Região de fluido (água, por exemplo) que faz os corpos boiarem.
+/// O empuxo é proporcional à área submersa de cada corpo (princípio de Arquimedes)
+/// e é aplicado no centroide da parte submersa, o que também gera torque.
+pub struct FluidRegion {
+    /// Forma da região (deve ser convexa)
+    pub polygon: Polygon,
+    /// Densidade do fluido (massa por unidade de área)
+    pub density: f64,
+    /// Arrasto linear (proporcional à fração submersa e à velocidade)
+    pub linear_drag: f64,
+    /// Arrasto angular (proporcional à fração submersa e à velocidade angular)
+    pub angular_drag: f64,
+    pub gravity: Vec2,
+}
+
+impl FluidRegion {
+    #[must_use]
+    /// Construtor da região com um polígono convexo
+    pub fn new(polygon: Polygon, density: f64, gravity: Vec2) -> FluidRegion {
+        FluidRegion {
+            polygon,
+            density,
+            linear_drag: 1.0,
+            angular_drag: 1.0,
+            gravity,
+        }
+    }
+
+    #[must_use]
+    /// Construtor da região com uma AABB
+    pub fn from_aabb(aabb: &AABB, density: f64, gravity: Vec2) -> FluidRegion {
+        FluidRegion::new(Polygon::from_aabb(aabb), density, gravity)
+    }
+
+    #[must_use]
+    /// Retorna a área submersa de um collider e o seu centroide (None se não estiver submerso).
+    /// O cálculo é exato: polígonos são recortados contra a região (Sutherland-Hodgman)
+    /// e círculos (colliders sem bordas) são intersectados com cada aresta da região.
+    pub fn submerged(&self, collider: &dyn Collider) -> Option<(f64, Vec2)> {
+        let edges = collider.edges();
+        let (area, centroid) = if edges.is_empty() {
+            // Círculo: size() = (raio, raio)
+            circle_polygon_intersection(
+                collider.center(),
+                collider.size().x,
+                &self.polygon.vertices,
+            )
+        } else {
+            let vertices: Vec<Vec2> = edges.into_iter().map(|edge| edge.p1).collect();
+            let clipped = Polygon::new(self.polygon.clip(&vertices));
+            if clipped.vertices.len() < 3 {
+                return None;
+            }
+            (clipped.area(), clipped.centroid())
+        };
+
+        if area > f64::EPSILON {
+            Some((area, centroid))
+        } else {
+            None
+        }
+    }
+
+    /// Aplica empuxo e arrasto em um corpo rígido durante `delta` segundos
+    pub fn apply<C: Collider>(&self, body: &mut RigidBody<C>, delta: f64) {
+        let Some((area, centroid)) = self.submerged(&body.collider) else {
+            return;
+        };
+
+        // Empuxo: peso do fluido deslocado, contra a gravidade
+        let buoyancy = -self.gravity * (self.density * area);
+        body.apply_force(buoyancy, centroid, delta);
+
+        // Arrasto proporcional à fração submersa do corpo
+        let fraction = (area / total_area(&body.collider)).clamp(0.0, 1.0);
+        let velocity = body.velocity_at(centroid);
+        body.apply_force(
+            -velocity * (self.linear_drag * fraction * body.mass),
+            centroid,
+            delta,
+        );
+        body.angular_velocity *= (1.0 - self.angular_drag * fraction * delta).max(0.0);
+    }
+
+    #[inline]
+    /// Desenha o contorno da região
+    pub fn draw(&self, thickness: f32, color: Color) {
+        self.polygon.draw(thickness, color);
+    }
+}
+
+/// (private) Área total de um collider
+fn total_area(collider: &dyn Collider) -> f64 {
+    let edges = collider.edges();
+    if edges.is_empty() {
+        let radius = collider.size().x;
+        std::f64::consts::PI * radius * radius
+    } else {
+        let vertices: Vec<Vec2> = edges.into_iter().map(|edge| edge.p1).collect();
+        Polygon::new(vertices).area()
+    }
+}
+
+/// (private) Área e centroide exatos da interseção entre um círculo e um polígono convexo.
+/// Soma, para cada aresta a-b, a área com sinal de `círculo ∩ triângulo(centro, a, b)`.
+fn circle_polygon_intersection(center: Vec2, radius: f64, polygon: &[Vec2]) -> (f64, Vec2) {
+    let n = polygon.len();
+    let (mut area, mut moment) = (0.0, Vec2::NULL);
+    for i in 0..n {
+        let (a, m) = circle_triangle(radius, polygon[i] - center, polygon[(i + 1) % n] - center);
+        area += a;
+        moment += m;
+    }
+
+    if area.abs() <= f64::EPSILON {
+        return (0.0, center);
+    }
+    (area.abs(), center + moment / area)
+}
+
+/// (private) Área com sinal e primeiro momento de `círculo ∩ triângulo(0, a, b)`,
+/// com o círculo centrado na origem. O segmento a-b é dividido nos pontos em que
+/// cruza o círculo: trechos dentro do círculo formam triângulos com a origem,
+/// trechos fora formam setores circulares.
+fn circle_triangle(radius: f64, a: Vec2, b: Vec2) -> (f64, Vec2) {
+    // |a + t(b - a)|² = r² -> t² |d|² + 2t (a·d) + |a|² - r² = 0
+    let d = b - a;
+    let (qa, qb, qc) = (
+        d.length_squared(),
+        2.0 * a.dot(d),
+        a.length_squared() - radius * radius,
+    );
+    let mut cuts = vec![0.0];
+    let discriminant = qb * qb - 4.0 * qa * qc;
+    if qa > 0.0 && discriminant > 0.0 {
+        let root = discriminant.sqrt();
+        for t in [(-qb - root) / (2.0 * qa), (-qb + root) / (2.0 * qa)] {
+            if 0.0 < t && t < 1.0 {
+                cuts.push(t);
+            }
+        }
+    }
+    cuts.push(1.0);
+
+    let (mut area, mut moment) = (0.0, Vec2::NULL);
+    for pair in cuts.windows(2) {
+        let (p, q) = (a + d * pair[0], a + d * pair[1]);
+        let middle = (p + q) / 2.0;
+        if middle.length_squared() <= radius * radius {
+            // Triângulo (0, p, q): centroide em (p + q) / 3
+            let triangle = p.cross(q) / 2.0;
+            area += triangle;
+            moment += (p + q) * (triangle / 3.0);
+        } else {
+            // Setor de ângulo theta: centroide a 4r sin(θ/2) / 3θ do centro, na bissetriz
+            let theta = p.cross(q).atan2(p.dot(q));
+            if theta.abs() <= f64::EPSILON {
+                continue;
+            }
+            let sector = radius * radius * theta / 2.0;
+            let distance = 4.0 * radius * (theta / 2.0).sin() / (3.0 * theta);
+            let bisector = p.normalized().rotated(theta / 2.0);
+            area += sector;
+            moment += bisector * (distance * sector);
+        }
+    }
+    (area, moment)
+}
@@ -0,0 +1,520 @@
+use std::f64::consts::FRAC_PI_4;
+
+use macroquad::{
+    color::Color,
+    shapes::{draw_circle_lines, draw_line, draw_rectangle_lines},
+};
+
+use crate::{
+    algebra::{Vec2, angular},
+    physics::{
+        platform::Platform,
+        shapes::{Collider, Line},
+    },
+};
+
+/// Forma do personagem (não gira)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CharacterShape {
+    /// Cápsula em pé: segmento de tamanho `2 * half_height` ao longo de `up`
+    /// com semicírculos de raio `radius` nas pontas
+    Capsule { radius: f64, half_height: f64 },
+    /// Caixa alinhada aos eixos com meia largura e meia altura
+    Box { half_size: Vec2 },
+}
+
+impl CharacterShape {
+    /// (private) Pontos do "núcleo" da forma (relativos ao centro) e o raio em volta deles
+    fn core(&self, up: Vec2) -> (Vec<Vec2>, f64) {
+        match *self {
+            CharacterShape::Capsule {
+                radius,
+                half_height,
+            } => (vec![up * half_height, -up * half_height], radius),
+            CharacterShape::Box { half_size } => (
+                vec![
+                    Vec2::new(-half_size.x, -half_size.y),
+                    Vec2::new(half_size.x, -half_size.y),
+                    Vec2::new(half_size.x, half_size.y),
+                    Vec2::new(-half_size.x, half_size.y),
+                ],
+                0.0,
+            ),
+        }
+    }
+}
+
+/// Controlador de personagem cinemático ("move and slide").
+/// O personagem não tem velocidade própria: cada chamada de `move_and_slide`
+/// recebe o movimento desejado, varre a forma contra as paredes e colliders,
+/// desliza ao longo das superfícies, sobe degraus pequenos e atualiza o estado de chão.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CharacterController {
+    pub position: Vec2,
+    pub shape: CharacterShape,
+    /// Direção "pra cima" normalizada (padrão -Y, como na tela)
+    pub up: Vec2,
+    /// Inclinação máxima (em radianos) de uma superfície que conta como chão
+    pub max_slope: f64,
+    /// Altura máxima de um degrau que o personagem sobe andando (0 desativa)
+    pub step_height: f64,
+    /// Folga mantida entre o personagem e as superfícies
+    pub skin: f64,
+    /// Número máximo de deslizes por movimento
+    pub max_iterations: usize,
+    grounded: bool,
+    ground_normal: Vec2,
+}
+
+impl CharacterController {
+    #[must_use]
+    /// Construtor do personagem (inclinação máxima de 45°, sem degraus)
+    pub fn new(position: Vec2, shape: CharacterShape) -> CharacterController {
+        CharacterController {
+            position,
+            shape,
+            up: -Vec2::Y,
+            max_slope: FRAC_PI_4,
+            step_height: 0.0,
+            skin: 0.01,
+            max_iterations: 4,
+            grounded: false,
+            ground_normal: -Vec2::Y,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna se o personagem está apoiado em um chão (superfície não muito inclinada)
+    pub fn is_grounded(&self) -> bool {
+        self.grounded
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a normal do chão em que o personagem está apoiado
+    pub fn ground_normal(&self) -> Option<Vec2> {
+        self.grounded.then_some(self.ground_normal)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se uma normal de contato é de uma superfície que conta como chão
+    pub fn is_walkable(&self, normal: Vec2) -> bool {
+        normal.dot(self.up) >= self.max_slope.cos() - 1e-9
+    }
+
+    /// Move o personagem o mais perto possível de `motion`, deslizando pelas superfícies.
+    /// Retorna o deslocamento realmente feito.
+    pub fn move_and_slide(
+        &mut self,
+        motion: Vec2,
+        walls: &[Line],
+        colliders: &[&dyn Collider],
+    ) -> Vec2 {
+        let start = self.position;
+        self.slide(motion, &obstacles(walls, colliders));
+        self.position - start
+    }
+
+    /// Como `move_and_slide`, mas também colide com plataformas (as de mão única só
+    /// bloqueiam vindo de cima) e é carregado pela plataforma em que está apoiado.
+    /// As plataformas já devem ter sido atualizadas neste passo.
+    pub fn move_with_platforms(
+        &mut self,
+        motion: Vec2,
+        walls: &[Line],
+        colliders: &[&dyn Collider],
+        platforms: &[Platform],
+    ) -> Vec2 {
+        let start = self.position;
+
+        // Anda junto com a plataforma em que estava apoiado (na posição anterior dela)
+        if self.grounded {
+            let carrier = platforms.iter().find(|platform| {
+                let previous = [Rounded::platform(platform, -platform.displacement())];
+                let probe = -self.up * platform.ride_distance.max(self.skin * 2.0);
+                self.sweep(self.position, probe, &previous)
+                    .is_some_and(|(_, normal)| self.is_walkable(normal))
+            });
+            if let Some(platform) = carrier {
+                self.position += platform.displacement();
+            }
+        }
+
+        let mut obstacles = obstacles(walls, colliders);
+        obstacles.extend(
+            platforms
+                .iter()
+                .map(|platform| Rounded::platform(platform, Vec2::NULL)),
+        );
+        self.slide(motion, &obstacles);
+        self.position - start
+    }
+
+    /// (private) Move e desliza o personagem contra as formas arredondadas
+    fn slide(&mut self, motion: Vec2, obstacles: &[Rounded]) {
+        let was_grounded = self.grounded;
+        self.grounded = false;
+        self.depenetrate(obstacles);
+
+        let mut remaining = motion;
+        for _ in 0..self.max_iterations {
+            if remaining.length_squared() <= 1e-18 {
+                break;
+            }
+            let Some((t, normal)) = self.sweep(self.position, remaining, obstacles) else {
+                self.position += remaining;
+                break;
+            };
+            self.position += self.safe_motion(remaining, t);
+            remaining *= 1.0 - t;
+
+            if self.is_walkable(normal) {
+                self.set_ground(normal);
+                remaining = self.along_floor(remaining, normal);
+            } else {
+                let on_ground = was_grounded || self.grounded;
+                // Paredes (não tetos) podem ser degraus
+                if on_ground
+                    && self.step_height > 0.0
+                    && normal.dot(self.up) > -1e-9
+                    && let Some(position) = self.step_up(remaining, obstacles)
+                {
+                    self.position = position;
+                    break;
+                }
+                remaining -= normal * remaining.dot(normal);
+                // Não escala rampas íngremes andando
+                if on_ground && normal.dot(self.up) > 0.0 && remaining.dot(self.up) > 0.0 {
+                    remaining -= self.up * remaining.dot(self.up);
+                }
+            }
+            self.depenetrate(obstacles);
+        }
+
+        // Gruda no chão ao descer rampas e degraus (a menos que esteja pulando)
+        if was_grounded && !self.grounded && self.step_height > 0.0 && motion.dot(self.up) <= 0.0 {
+            let down = -self.up * self.step_height;
+            if let Some((t, normal)) = self.sweep(self.position, down, obstacles)
+                && self.is_walkable(normal)
+            {
+                self.position += self.safe_motion(down, t);
+                self.set_ground(normal);
+            }
+        }
+
+        // Sonda o chão logo abaixo do personagem
+        if !self.grounded {
+            let probe = -self.up * (self.skin * 2.0);
+            if let Some((_, normal)) = self.sweep(self.position, probe, obstacles)
+                && self.is_walkable(normal)
+            {
+                self.set_ground(normal);
+            }
+        }
+    }
+
+    /// Desenha o contorno do personagem
+    pub fn draw(&self, thickness: f32, color: Color) {
+        let p = self.position;
+        match self.shape {
+            CharacterShape::Capsule {
+                radius,
+                half_height,
+            } => {
+                let (top, bottom) = (p + self.up * half_height, p - self.up * half_height);
+                let side = Vec2::new(-self.up.y, self.up.x) * radius;
+                for center in [top, bottom] {
+                    draw_circle_lines(
+                        center.x as f32,
+                        center.y as f32,
+                        radius as f32,
+                        thickness,
+                        color,
+                    );
+                }
+                for offset in [side, -side] {
+                    let (a, b) = (top + offset, bottom + offset);
+                    draw_line(
+                        a.x as f32, a.y as f32, b.x as f32, b.y as f32, thickness, color,
+                    );
+                }
+            }
+            CharacterShape::Box { half_size } => draw_rectangle_lines(
+                (p.x - half_size.x) as f32,
+                (p.y - half_size.y) as f32,
+                (half_size.x * 2.0) as f32,
+                (half_size.y * 2.0) as f32,
+                thickness,
+                color,
+            ),
+        }
+    }
+
+    #[inline]
+    /// (private) Marca o personagem como apoiado no chão
+    fn set_ground(&mut self, normal: Vec2) {
+        self.grounded = true;
+        self.ground_normal = normal;
+    }
+
+    #[inline]
+    /// (private) Parte segura de um movimento até o contato em `t` (mantém a folga)
+    fn safe_motion(&self, motion: Vec2, t: f64) -> Vec2 {
+        let length = motion.length();
+        if length <= 0.0 {
+            return Vec2::NULL;
+        }
+        motion * (t - self.skin / length).max(0.0)
+    }
+
+    /// (private) Projeta o movimento lateral no plano do chão, descartando a parte
+    /// vertical (assim a gravidade não faz o personagem escorregar pela rampa)
+    fn along_floor(&self, motion: Vec2, normal: Vec2) -> Vec2 {
+        let lateral = motion - self.up * motion.dot(self.up);
+        lateral - self.up * (lateral.dot(normal) / self.up.dot(normal))
+    }
+
+    /// (private) Tenta subir um degrau: sobe `step_height`, anda e desce até um chão.
+    /// Retorna a nova posição se o personagem avançou e terminou em um chão.
+    fn step_up(&mut self, motion: Vec2, obstacles: &[Rounded]) -> Option<Vec2> {
+        let lateral = motion - self.up * motion.dot(self.up);
+        if lateral.length_squared() <= 1e-18 {
+            return None;
+        }
+
+        let mut position = self.position;
+        position += self.clamped_motion(position, self.up * self.step_height, obstacles);
+        position += self.clamped_motion(position, lateral, obstacles);
+
+        let down = -self.up * ((position - self.position).dot(self.up) + self.skin * 2.0);
+        let (t, normal) = self.sweep(position, down, obstacles)?;
+        if !self.is_walkable(normal) {
+            return None;
+        }
+        position += self.safe_motion(down, t);
+
+        if (position - self.position).dot(lateral.normalized()) <= self.skin {
+            return None;
+        }
+        self.set_ground(normal);
+        Some(position)
+    }
+
+    #[inline]
+    /// (private) Movimento a partir de `position` que para antes do primeiro contato
+    fn clamped_motion(&self, position: Vec2, motion: Vec2, obstacles: &[Rounded]) -> Vec2 {
+        match self.sweep(position, motion, obstacles) {
+            Some((t, _)) => self.safe_motion(motion, t),
+            None => motion,
+        }
+    }
+
+    /// (private) Varre a forma de `position` até `position + motion`.
+    /// Retorna o primeiro contato (fração do movimento, normal) se houver.
+    fn sweep(&self, position: Vec2, motion: Vec2, obstacles: &[Rounded]) -> Option<(f64, Vec2)> {
+        let (core, radius) = self.shape.core(self.up);
+        obstacles
+            .iter()
+            .filter_map(|obstacle| {
+                let hull = obstacle.minkowski(position, &core);
+                let radius = obstacle.radius + radius;
+                let Some(up) = obstacle.one_way else {
+                    return ray_cast(&hull, radius, motion);
+                };
+                // Mão única: ignora se já está atravessando ou se o contato não é por cima
+                if signed_distance(&hull, radius).0 < -self.skin {
+                    return None;
+                }
+                ray_cast(&hull, radius, motion).filter(|(_, normal)| normal.dot(up) > 0.0)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+    }
+
+    /// (private) Empurra o personagem para fora de superfícies que ele já atravessou
+    fn depenetrate(&mut self, obstacles: &[Rounded]) {
+        let (core, radius) = self.shape.core(self.up);
+        for _ in 0..self.max_iterations {
+            let mut moved = false;
+            for obstacle in obstacles {
+                let hull = obstacle.minkowski(self.position, &core);
+                let (distance, normal) = signed_distance(&hull, obstacle.radius + radius);
+                let blocks = obstacle
+                    .one_way
+                    .is_none_or(|up| distance >= -self.skin && normal.dot(up) > 0.0);
+                if blocks && distance < self.skin / 2.0 {
+                    self.position += normal * (self.skin - distance);
+                    moved = true;
+                    if self.is_walkable(normal) {
+                        self.set_ground(normal);
+                    }
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+    }
+}
+
+/// (private) Forma convexa "arredondada": fecho convexo de pontos com um raio em volta.
+/// Linhas são segmentos de raio 0, círculos são um ponto com raio.
+struct Rounded {
+    points: Vec<Vec2>,
+    radius: f64,
+    /// Direção de onde uma forma de mão única bloqueia
+    one_way: Option<Vec2>,
+}
+
+impl Rounded {
+    /// (private) Forma de uma plataforma deslocada por `offset`
+    fn platform(platform: &Platform, offset: Vec2) -> Rounded {
+        Rounded {
+            points: platform
+                .polygon
+                .vertices
+                .iter()
+                .map(|v| *v + offset)
+                .collect(),
+            radius: 0.0,
+            one_way: platform.one_way.then_some(platform.up),
+        }
+    }
+
+    /// (private) Fecho convexo da diferença de Minkowski `obstáculo - personagem`,
+    /// relativa à posição do personagem. O personagem colide quando a origem
+    /// fica a menos de `raio` desse fecho.
+    fn minkowski(&self, position: Vec2, core: &[Vec2]) -> Vec<Vec2> {
+        let mut points: Vec<Vec2> = self
+            .points
+            .iter()
+            .flat_map(|p| core.iter().map(move |c| *p - position - *c))
+            .collect();
+        // Junta os pontos quase iguais antes do fecho (threshold de `is_same`)
+        points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        points.dedup_by(|a, b| a.is_same(*b));
+        angular::convex_hull(&points)
+    }
+}
+
+/// (private) Converte paredes e colliders em formas arredondadas
+fn obstacles(walls: &[Line], colliders: &[&dyn Collider]) -> Vec<Rounded> {
+    let walls = walls.iter().map(|wall| Rounded {
+        points: vec![wall.p1, wall.p2],
+        radius: 0.0,
+        one_way: None,
+    });
+    let colliders = colliders.iter().map(|collider| {
+        let edges = collider.edges();
+        if edges.is_empty() {
+            // Círculo: size() = (raio, raio)
+            Rounded {
+                points: vec![collider.center()],
+                radius: collider.size().x,
+                one_way: None,
+            }
+        } else {
+            Rounded {
+                points: edges.into_iter().map(|edge| edge.p1).collect(),
+                radius: 0.0,
+                one_way: None,
+            }
+        }
+    });
+    walls.chain(colliders).collect()
+}
+
+/// (private) Arestas do fecho com a normal para fora
+fn hull_edges(hull: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2, Vec2)> + '_ {
+    let n = hull.len();
+    (0..n).filter_map(move |i| {
+        let (p, q) = (hull[i], hull[(i + 1) % n]);
+        let e = q - p;
+        if e.length_squared() <= 1e-24 {
+            return None;
+        }
+        Some((p, q, Vec2::new(e.y, -e.x).normalized()))
+    })
+}
+
+/// (private) Distância com sinal da origem até o fecho arredondado (negativa se dentro)
+/// e a direção para fora do fecho
+fn signed_distance(hull: &[Vec2], radius: f64) -> (f64, Vec2) {
+    if hull.len() == 1 {
+        let d = -hull[0];
+        let length = d.length();
+        let normal = if length > 0.0 { d / length } else { Vec2::Y };
+        return (length - radius, normal);
+    }
+
+    // Origem dentro do fecho: a aresta mais próxima define a saída
+    if hull.len() >= 3 && hull_edges(hull).all(|(p, _, n)| (-p).dot(n) <= 0.0) {
+        let (depth, normal) = hull_edges(hull)
+            .map(|(p, _, n)| ((-p).dot(n), n))
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap_or((0.0, Vec2::Y));
+        return (depth - radius, normal);
+    }
+
+    let (closest, normal) = hull_edges(hull)
+        .map(|(p, q, n)| (Line::new(p, q).closest_point(Vec2::NULL), n))
+        .min_by(|a, b| a.0.length_squared().total_cmp(&b.0.length_squared()))
+        .unwrap_or((Vec2::NULL, Vec2::Y));
+    let distance = closest.length();
+    if distance > 1e-12 {
+        (distance - radius, -closest / distance)
+    } else {
+        (-radius, normal)
+    }
+}
+
+/// (private) Raio da origem ao longo de `motion` (t em [0,1]) contra o fecho arredondado.
+/// Retorna o t do contato e a normal da superfície.
+fn ray_cast(hull: &[Vec2], radius: f64, motion: Vec2) -> Option<(f64, Vec2)> {
+    if hull.is_empty() {
+        return None;
+    }
+    let (distance, normal) = signed_distance(hull, radius);
+    if distance <= 0.0 {
+        // Já em contato: só bloqueia se estiver entrando na superfície
+        return (motion.dot(normal) < 0.0).then_some((0.0, normal));
+    }
+
+    let mut best: Option<(f64, Vec2)> = None;
+    let mut consider = |t: f64, normal: Vec2| {
+        if (0.0..=1.0).contains(&t) && best.is_none_or(|(best_t, _)| t < best_t) {
+            best = Some((t, normal));
+        }
+    };
+
+    // Arestas deslocadas pelo raio
+    for (p, q, n) in hull_edges(hull) {
+        let dn = motion.dot(n);
+        if dn >= 0.0 {
+            continue;
+        }
+        let t = (p.dot(n) + radius) / dn;
+        let e = q - p;
+        let s = (motion * t - n * radius - p).dot(e) / e.length_squared();
+        if (-1e-9..=1.0 + 1e-9).contains(&s) {
+            consider(t, n);
+        }
+    }
+
+    // Círculos nos vértices
+    if radius > 0.0 {
+        let a = motion.length_squared();
+        for &v in hull {
+            let b = -2.0 * motion.dot(v);
+            let c = v.length_squared() - radius * radius;
+            let discriminant = b * b - 4.0 * a * c;
+            if a <= 0.0 || discriminant < 0.0 {
+                continue;
+            }
+            let t = (-b - discriminant.sqrt()) / (2.0 * a);
+            consider(t, (motion * t - v) / radius);
+        }
+    }
+    best
+}
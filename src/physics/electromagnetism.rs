@@ -0,0 +1,122 @@
+use rayon::prelude::*;
+
+use crate::{
+    algebra::Vec2,
+    physics::shapes::{Line, Particle},
+};
+
+/// Fonte de campo elétrico
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ElectricField {
+    /// Campo uniforme (igual em todos os pontos)
+    Uniform(Vec2),
+    /// Carga pontual fixa em uma posição
+    Point { position: Vec2, charge: f64 },
+}
+
+/// Campos elétricos e magnéticos agindo sobre partículas carregadas.
+/// A força em cada partícula é a força de Lorentz `F = q(E + v × B)`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Electromagnetism {
+    pub electric: Vec<ElectricField>,
+    /// Campo magnético uniforme perpendicular ao plano (componente z).
+    /// Partículas carregadas fazem círculos de raio `m|v| / |qB|`.
+    /// (Com o eixo y pra baixo, como na tela, o sentido de giro aparece invertido.)
+    pub magnetic: f64,
+    /// Constante de Coulomb `k` (usada pelas cargas pontuais e entre partículas)
+    pub coulomb: f64,
+    /// Suavização da distância (evita forças infinitas em encontros próximos)
+    pub softening: f64,
+    /// Se as partículas interagem entre si pela lei de Coulomb (O(n²), paralelo)
+    pub interactions: bool,
+}
+
+impl Electromagnetism {
+    #[must_use]
+    /// Construtor sem campos e sem interação entre partículas
+    pub fn new(coulomb: f64, softening: f64) -> Electromagnetism {
+        Electromagnetism {
+            electric: Vec::new(),
+            magnetic: 0.0,
+            coulomb,
+            softening,
+            interactions: false,
+        }
+    }
+
+    #[must_use]
+    /// Retorna o campo elétrico externo em um ponto
+    pub fn field_at(&self, point: Vec2) -> Vec2 {
+        self.electric
+            .iter()
+            .map(|field| match *field {
+                ElectricField::Uniform(e) => e,
+                ElectricField::Point { position, charge } => {
+                    self.coulomb_field(point, position, charge)
+                }
+            })
+            .sum()
+    }
+
+    /// Atualiza as velocidades das partículas carregadas (sem movê-las).
+    /// Usa o integrador de Boris, que preserva a velocidade no campo magnético
+    /// (as órbitas circulares não crescem nem encolhem com o tempo).
+    pub fn apply(&self, particles: &mut [Particle], delta: f64) {
+        // Campo elétrico total em cada partícula (externo + outras partículas)
+        let fields: Vec<Vec2> = particles
+            .par_iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let mut field = self.field_at(p.position);
+                if self.interactions {
+                    field += particles
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, other)| *j != i && other.charge != 0.0)
+                        .map(|(_, other)| {
+                            self.coulomb_field(p.position, other.position, other.charge)
+                        })
+                        .sum::<Vec2>();
+                }
+                field
+            })
+            .collect();
+
+        particles
+            .par_iter_mut()
+            .zip(fields.par_iter())
+            .filter(|(p, _)| p.charge != 0.0)
+            .for_each(|(particle, field)| {
+                let q_over_m = particle.charge / particle.mass;
+                // Meio impulso elétrico
+                let half_kick = *field * (q_over_m * delta / 2.0);
+                let v_minus = particle.speed + half_kick;
+
+                // Rotação magnética: v × (0, 0, B) = (v.y B, -v.x B)
+                let t = q_over_m * self.magnetic * delta / 2.0;
+                let s = 2.0 * t / (1.0 + t * t);
+                let v_prime = v_minus + Vec2::new(v_minus.y, -v_minus.x) * t;
+                let v_plus = v_minus + Vec2::new(v_prime.y, -v_prime.x) * s;
+
+                // Outro meio impulso elétrico
+                particle.speed = v_plus + half_kick;
+            });
+    }
+
+    /// Aplica os campos e move as partículas contra as paredes
+    pub fn step(&self, particles: &mut [Particle], delta: f64, walls: &[Line]) {
+        self.apply(particles, delta);
+        Particle::par_update(particles, delta, walls);
+    }
+
+    #[inline]
+    /// (private) Campo em `point` de uma carga em `source`: `k q d / (|d|² + ε²)^(3/2)`
+    fn coulomb_field(&self, point: Vec2, source: Vec2, charge: f64) -> Vec2 {
+        let d = point - source;
+        let distance_squared = d.length_squared() + self.softening * self.softening;
+        if distance_squared <= 0.0 {
+            return Vec2::NULL;
+        }
+        d * (self.coulomb * charge / (distance_squared * distance_squared.sqrt()))
+    }
+}
@@ -0,0 +1,157 @@
+use macroquad::color::Color;
+
+use crate::{
+    algebra::Vec2,
+    physics::{
+        generators::{random_point, random_point_radial},
+        shapes::{Particle, Wall},
+    },
+    utils::randf_range,
+};
+
+/// Formato da região onde as partículas nascem
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EmitterShape {
+    /// Todas as partículas nascem na posição do emissor
+    Point,
+    /// Partículas nascem em um retângulo centrado no emissor
+    Rect { half_size: Vec2 },
+    /// Partículas nascem em um raio ao redor do emissor
+    Radial { radius: f64 },
+}
+
+/// Uma partícula gerenciada por um emissor (com idade e tempo de vida)
+#[derive(Clone, Copy, PartialEq)]
+pub struct EmittedParticle {
+    pub particle: Particle,
+    pub age: f64,
+    pub lifetime: f64,
+}
+
+impl EmittedParticle {
+    #[inline]
+    #[must_use]
+    /// Checa se a partícula ainda está viva
+    pub fn is_alive(&self) -> bool {
+        self.age < self.lifetime
+    }
+}
+
+/// Emissor de partículas. Gera partículas com uma taxa contínua e/ou em rajadas,
+/// dentro de um cone de direções, e gerencia a sua "piscina" de partículas vivas.
+pub struct Emitter {
+    pub position: Vec2,
+    pub shape: EmitterShape,
+    /// Partículas geradas por segundo
+    pub rate: f64,
+    /// Ângulo (em radianos) da direção central do cone de emissão
+    pub direction: f64,
+    /// Metade da abertura do cone de emissão (PI = todas as direções)
+    pub spread: f64,
+    /// Velocidade mínima e máxima das partículas geradas
+    pub min_speed: f64,
+    pub max_speed: f64,
+    /// Tempo de vida mínimo e máximo das partículas geradas (em segundos)
+    pub min_lifetime: f64,
+    pub max_lifetime: f64,
+    /// Número máximo de partículas vivas ao mesmo tempo
+    pub max_particles: usize,
+    /// Partículas vivas
+    pub particles: Vec<EmittedParticle>,
+    /// Tempo acumulado desde a última partícula gerada pela taxa contínua
+    accumulator: f64,
+}
+
+impl Emitter {
+    #[must_use]
+    /// Construtor do emissor. Começa sem emissão contínua (rate = 0), emitindo em todas
+    /// as direções com velocidade `speed` e partículas que vivem para sempre.
+    pub fn new(position: Vec2, shape: EmitterShape, speed: f64) -> Emitter {
+        Emitter {
+            position,
+            shape,
+            rate: 0.0,
+            direction: 0.0,
+            spread: std::f64::consts::PI,
+            min_speed: speed,
+            max_speed: speed,
+            min_lifetime: f64::INFINITY,
+            max_lifetime: f64::INFINITY,
+            max_particles: 10_000,
+            particles: Vec::new(),
+            accumulator: 0.0,
+        }
+    }
+
+    /// Gera `count` partículas imediatamente (respeitando o máximo de partículas)
+    pub fn burst(&mut self, count: usize) {
+        let count = count.min(self.max_particles.saturating_sub(self.particles.len()));
+        for _ in 0..count {
+            let particle = self.spawn();
+            self.particles.push(particle);
+        }
+    }
+
+    /// Gera novas partículas conforme a taxa de emissão, atualiza as partículas vivas
+    /// contra as paredes e remove as que morreram (por idade ou por uma parede).
+    pub fn update(&mut self, delta: f64, walls: &[Wall]) {
+        // Emissão contínua: acumula o tempo e gera uma partícula a cada 1/rate segundos
+        if self.rate > 0.0 {
+            self.accumulator += delta;
+            let interval = 1.0 / self.rate;
+            let count = (self.accumulator / interval).floor();
+            self.accumulator -= count * interval;
+            self.burst(count as usize);
+        } else {
+            self.accumulator = 0.0;
+        }
+
+        // Remove as partículas mortas trocando-as com a última (O(1), não preserva a ordem)
+        let mut i = 0;
+        while i < self.particles.len() {
+            let emitted = &mut self.particles[i];
+            emitted.age += delta;
+            if emitted.is_alive() && emitted.particle.update_with_walls(delta, walls) {
+                i += 1;
+            } else {
+                self.particles.swap_remove(i);
+            }
+        }
+    }
+
+    #[inline]
+    /// Desenha todas as partículas vivas
+    pub fn draw(&self, color: Color) {
+        for emitted in &self.particles {
+            emitted.particle.draw(color);
+        }
+    }
+
+    /// (private) Gera uma nova partícula de acordo com os parâmetros do emissor
+    fn spawn(&self) -> EmittedParticle {
+        let position = match self.shape {
+            EmitterShape::Point => self.position,
+            EmitterShape::Rect { half_size } => {
+                let min = self.position - half_size;
+                let max = self.position + half_size;
+                random_point(min.x, min.y, max.x, max.y)
+            }
+            EmitterShape::Radial { radius } => random_point_radial(self.position, radius),
+        };
+
+        let angle = self.direction + sample(-self.spread, self.spread);
+        let speed = Vec2::from_angle(angle) * sample(self.min_speed, self.max_speed);
+
+        EmittedParticle {
+            particle: Particle::new(position, speed),
+            age: 0.0,
+            lifetime: sample(self.min_lifetime, self.max_lifetime),
+        }
+    }
+}
+
+/// (private) Valor aleatório entre min e max (retorna min se o intervalo for vazio,
+/// o que também evita NaN com tempos de vida infinitos)
+fn sample(min: f64, max: f64) -> f64 {
+    if min < max { randf_range(min, max) } else { min }
+}
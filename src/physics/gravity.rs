@@ -0,0 +1,312 @@
+use rayon::prelude::*;
+
+use crate::{
+    algebra::Vec2,
+    physics::shapes::{Line, Particle},
+};
+
+/// Profundidade máxima da quadtree (partículas quase coincidentes viram uma folha agregada)
+const MAX_DEPTH: usize = 48;
+
+/// Forma de calcular a atração gravitacional entre as partículas
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GravityMode {
+    /// Aproximação de Barnes-Hut em O(n log n). Um nó da árvore de tamanho `s` a uma
+    /// distância `d` é tratado como uma massa só quando `s / d < theta`
+    /// (theta = 0 equivale ao modo exato).
+    BarnesHut { theta: f64 },
+    /// Soma exata de todos os pares em O(n²) (paralela, para validação)
+    Exact,
+}
+
+/// Gravidade mútua entre partículas (problema de N corpos)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NBody {
+    /// Constante gravitacional
+    pub g: f64,
+    /// Suavização da distância (evita forças infinitas em encontros próximos):
+    /// `a = G m d / (|d|² + softening²)^(3/2)`
+    pub softening: f64,
+    pub mode: GravityMode,
+}
+
+impl NBody {
+    #[inline]
+    #[must_use]
+    /// Construtor com Barnes-Hut (theta = 0.5)
+    pub fn new(g: f64, softening: f64) -> NBody {
+        NBody {
+            g,
+            softening,
+            mode: GravityMode::BarnesHut { theta: 0.5 },
+        }
+    }
+
+    #[must_use]
+    /// Retorna a aceleração gravitacional sobre cada partícula.
+    /// O resultado é determinístico (não depende do número de threads).
+    pub fn accelerations(&self, particles: &[Particle]) -> Vec<Vec2> {
+        match self.mode {
+            GravityMode::Exact => particles
+                .par_iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    particles
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, _)| *j != i)
+                        .map(|(_, other)| self.attraction(p.position, other.position, other.mass))
+                        .sum()
+                })
+                .collect(),
+            GravityMode::BarnesHut { theta } => {
+                let tree = QuadTree::build(particles);
+                particles
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, p)| tree.acceleration(self, i, p.position, theta))
+                    .collect()
+            }
+        }
+    }
+
+    /// Aplica a gravidade nas velocidades das partículas (sem movê-las)
+    pub fn apply(&self, particles: &mut [Particle], delta: f64) {
+        let accelerations = self.accelerations(particles);
+        particles
+            .par_iter_mut()
+            .zip(accelerations.par_iter())
+            .for_each(|(particle, acceleration)| particle.speed += *acceleration * delta);
+    }
+
+    /// Aplica a gravidade e move as partículas contra as paredes (Euler semi-implícito)
+    pub fn step(&self, particles: &mut [Particle], delta: f64, walls: &[Line]) {
+        self.apply(particles, delta);
+        Particle::par_update(particles, delta, walls);
+    }
+
+    #[inline]
+    /// (private) Aceleração em `position` causada por uma massa em `source`
+    fn attraction(&self, position: Vec2, source: Vec2, mass: f64) -> Vec2 {
+        let d = source - position;
+        let distance_squared = d.length_squared() + self.softening * self.softening;
+        if distance_squared <= 0.0 {
+            return Vec2::NULL;
+        }
+        d * (self.g * mass / (distance_squared * distance_squared.sqrt()))
+    }
+}
+
+/// Nó da quadtree. Guarda a massa total e o centro de massa de tudo abaixo dele.
+struct Node {
+    min: Vec2,
+    size: f64,
+    mass: f64,
+    /// Soma de `massa * posição` (centro de massa = weighted / mass)
+    weighted: Vec2,
+    /// Índice do primeiro dos 4 filhos (consecutivos no vetor de nós)
+    children: Option<usize>,
+    /// Partículas guardadas em uma folha (mais de uma só nas folhas agregadas
+    /// da profundidade máxima)
+    bodies: Vec<usize>,
+}
+
+impl Node {
+    /// (private) Nó vazio
+    fn new(min: Vec2, size: f64) -> Node {
+        Node {
+            min,
+            size,
+            mass: 0.0,
+            weighted: Vec2::NULL,
+            children: None,
+            bodies: Vec::new(),
+        }
+    }
+
+    /// (private) Checa se um ponto está dentro do quadrado do nó
+    fn contains(&self, point: Vec2) -> bool {
+        let max = self.min + Vec2::new(self.size, self.size);
+        point.x >= self.min.x && point.x < max.x && point.y >= self.min.y && point.y < max.y
+    }
+
+    /// (private) Índice (0..4) do quadrante do nó que contém um ponto
+    fn quadrant(&self, point: Vec2) -> usize {
+        let half = self.size / 2.0;
+        let right = (point.x >= self.min.x + half) as usize;
+        let bottom = (point.y >= self.min.y + half) as usize;
+        right + 2 * bottom
+    }
+}
+
+/// Quadtree de Barnes-Hut (nós guardados em um vetor, sem ponteiros)
+struct QuadTree<'a> {
+    nodes: Vec<Node>,
+    particles: &'a [Particle],
+}
+
+impl<'a> QuadTree<'a> {
+    /// (private) Monta a árvore em um quadrado que contém todas as partículas
+    fn build(particles: &'a [Particle]) -> QuadTree<'a> {
+        let (mut min, mut max) = (
+            Vec2::new(f64::INFINITY, f64::INFINITY),
+            Vec2::new(-f64::INFINITY, -f64::INFINITY),
+        );
+        for p in particles {
+            min = Vec2::new(min.x.min(p.position.x), min.y.min(p.position.y));
+            max = Vec2::new(max.x.max(p.position.x), max.y.max(p.position.y));
+        }
+        let size = (max.x - min.x).max(max.y - min.y).max(f64::EPSILON) * 1.0001;
+
+        let mut tree = QuadTree {
+            nodes: vec![Node::new(min, size)],
+            particles,
+        };
+        for i in 0..particles.len() {
+            tree.insert(i);
+        }
+        tree
+    }
+
+    /// (private) Insere uma partícula descendo da raiz
+    fn insert(&mut self, body: usize) {
+        let (position, mass) = (self.particles[body].position, self.particles[body].mass);
+        let mut node = 0;
+        let mut depth = 0;
+
+        loop {
+            // Todos os nós do caminho acumulam a massa da partícula
+            self.nodes[node].mass += mass;
+            self.nodes[node].weighted += position * mass;
+
+            if let Some(first_child) = self.nodes[node].children {
+                node = first_child + self.nodes[node].quadrant(position);
+                depth += 1;
+                continue;
+            }
+
+            // Folha vazia, ou na profundidade máxima (vira um agregado das partículas):
+            // guarda a partícula aqui
+            if self.nodes[node].bodies.is_empty() || depth >= MAX_DEPTH {
+                self.nodes[node].bodies.push(body);
+                return;
+            }
+
+            // Folha ocupada: divide em 4 e empurra a partícula existente para um filho
+            let (min, half) = (self.nodes[node].min, self.nodes[node].size / 2.0);
+            let first_child = self.nodes.len();
+            for q in 0..4 {
+                let offset = Vec2::new((q % 2) as f64 * half, (q / 2) as f64 * half);
+                self.nodes.push(Node::new(min + offset, half));
+            }
+            self.nodes[node].children = Some(first_child);
+            let existing = self.nodes[node].bodies.pop().unwrap();
+
+            let existing_particle = self.particles[existing];
+            let child = first_child + self.nodes[node].quadrant(existing_particle.position);
+            self.nodes[child].mass += existing_particle.mass;
+            self.nodes[child].weighted += existing_particle.position * existing_particle.mass;
+            self.nodes[child].bodies.push(existing);
+
+            node = first_child + self.nodes[node].quadrant(position);
+            depth += 1;
+        }
+    }
+
+    /// (private) Aceleração sobre a partícula `body` percorrendo a árvore
+    fn acceleration(&self, nbody: &NBody, body: usize, position: Vec2, theta: f64) -> Vec2 {
+        let mut total = Vec2::NULL;
+        let mut stack = vec![0];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.mass <= 0.0 {
+                continue;
+            }
+
+            let center_of_mass = node.weighted / node.mass;
+            let distance = center_of_mass.distance_to(position);
+            match node.children {
+                // Nó perto demais, ou que contém a própria partícula: desce para os filhos
+                Some(first_child) if node.size >= theta * distance || node.contains(position) => {
+                    stack.extend(first_child..first_child + 4);
+                }
+                // Nó longe o suficiente: conta como uma massa só
+                Some(_) => total += nbody.attraction(position, center_of_mass, node.mass),
+                // Folha: soma cada partícula separadamente, sem contar a própria
+                // (uma folha agregada pode ter outras partículas quase no mesmo ponto)
+                None => {
+                    for &other in node.bodies.iter().filter(|other| **other != body) {
+                        let other = &self.particles[other];
+                        total += nbody.attraction(position, other.position, other.mass);
+                    }
+                }
+            }
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compara Barnes-Hut com theta pequeno contra a soma exata
+    fn assert_matches_exact(particles: &[Particle], softening: f64) {
+        let exact = NBody {
+            mode: GravityMode::Exact,
+            ..NBody::new(1.0, softening)
+        };
+        let tree = NBody {
+            mode: GravityMode::BarnesHut { theta: 0.1 },
+            ..exact
+        };
+        let (expected, actual) = (
+            exact.accelerations(particles),
+            tree.accelerations(particles),
+        );
+        for (i, (e, a)) in expected.iter().zip(&actual).enumerate() {
+            let error = (*e - *a).length();
+            assert!(
+                error <= 1e-6 * e.length().max(1e-12),
+                "partícula {i}: {a:?} != {e:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn almost_coincident_bodies_attract_each_other() {
+        // Os três primeiros corpos caem na mesma folha agregada da profundidade máxima
+        let particles = [
+            Particle::new(Vec2::new(0.0, 0.0), Vec2::NULL),
+            Particle::with_mass(Vec2::new(1e-13, 0.0), Vec2::NULL, 2.0),
+            Particle::with_mass(Vec2::new(0.0, 2e-13), Vec2::NULL, 3.0),
+            Particle::new(Vec2::new(100.0, 50.0), Vec2::NULL),
+        ];
+        assert_matches_exact(&particles, 0.0);
+        assert_matches_exact(&particles, 1e-14);
+    }
+
+    #[test]
+    fn barnes_hut_matches_exact_for_spread_bodies() {
+        let particles: Vec<Particle> = (0..200)
+            .map(|i| {
+                let t = i as f64 * 0.731;
+                let position = Vec2::new(t.cos() * (10.0 + t), t.sin() * (10.0 + t));
+                Particle::with_mass(position, Vec2::NULL, 1.0 + (i % 3) as f64)
+            })
+            .collect();
+        let exact = NBody {
+            mode: GravityMode::Exact,
+            ..NBody::new(1.0, 0.1)
+        };
+        let tree = NBody::new(1.0, 0.1);
+        let (expected, actual) = (
+            exact.accelerations(&particles),
+            tree.accelerations(&particles),
+        );
+        for (e, a) in expected.iter().zip(&actual) {
+            assert!((*e - *a).length() <= 0.05 * e.length());
+        }
+    }
+}
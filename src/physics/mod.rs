@@ -1,14 +1,28 @@
-use crate::algebra::Vec2;
+use crate::algebra::{Scalar, Vec2};
 pub mod shapes;
+pub mod shapes3d;
 pub mod generators;
+pub mod emitter;
+pub mod particle_system;
+pub mod verlet;
+pub mod softbody;
+pub mod sph;
+pub mod xpbd;
+pub mod gravity;
+pub mod electromagnetism;
+pub mod body;
+pub mod buoyancy;
+pub mod character;
+pub mod vehicle;
+pub mod platform;
 
-pub struct Intersection {
+pub struct Intersection<T: Scalar = f64> {
     /// O t da interseção em r1(t) (interseção do "raio reta1" com a reta2)
-    pub t: f64,
+    pub t: T,
     /// O u da interseção em r2(t) (interseção do "raio reta2" com a reta1)
-    pub u: f64,
+    pub u: T,
     /// O ponto da interseção
-    pub p: Vec2,
+    pub p: Vec2<T>,
     /// A normal da interseção
-    pub normal: Vec2,
+    pub normal: Vec2<T>,
 }
@@ -0,0 +1,145 @@
+use macroquad::{color::Color, shapes::draw_circle};
+use rayon::prelude::*;
+
+use crate::{
+    algebra::Vec2,
+    physics::shapes::{Particle, Wall},
+};
+
+/// Sistema de partículas em formato "structure of arrays": posições, velocidades,
+/// idades e tempos de vida ficam em vetores contíguos separados.
+/// Criar partículas é O(1) e partículas mortas são removidas com swap-remove
+/// (a ordem das partículas não é preservada).
+pub struct ParticleSystem {
+    pub positions: Vec<Vec2>,
+    pub velocities: Vec<Vec2>,
+    pub ages: Vec<f64>,
+    pub lifetimes: Vec<f64>,
+    /// Número máximo de partículas vivas ao mesmo tempo
+    capacity: usize,
+}
+
+impl ParticleSystem {
+    #[must_use]
+    /// Cria um sistema vazio com memória reservada para `capacity` partículas
+    pub fn new(capacity: usize) -> ParticleSystem {
+        ParticleSystem {
+            positions: Vec::with_capacity(capacity),
+            velocities: Vec::with_capacity(capacity),
+            ages: Vec::with_capacity(capacity),
+            lifetimes: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o número de partículas vivas
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se não há partículas vivas
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o número máximo de partículas vivas
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    /// Cria uma nova partícula em O(1).
+    /// Retorna `false` (e não cria nada) se o sistema estiver cheio.
+    pub fn spawn(&mut self, position: Vec2, velocity: Vec2, lifetime: f64) -> bool {
+        if self.len() >= self.capacity {
+            return false;
+        }
+        self.positions.push(position);
+        self.velocities.push(velocity);
+        self.ages.push(0.0);
+        self.lifetimes.push(lifetime);
+        true
+    }
+
+    #[inline]
+    /// Remove a partícula `index` trocando-a com a última (O(1))
+    pub fn kill(&mut self, index: usize) {
+        self.positions.swap_remove(index);
+        self.velocities.swap_remove(index);
+        self.ages.swap_remove(index);
+        self.lifetimes.swap_remove(index);
+    }
+
+    /// Remove todas as partículas
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.velocities.clear();
+        self.ages.clear();
+        self.lifetimes.clear();
+    }
+
+    /// Atualiza todas as partículas contra as paredes do mundo e remove as que morreram
+    /// (por idade ou por atingirem uma parede que mata partículas)
+    pub fn update(&mut self, delta: f64, walls: &[Wall]) {
+        // Envelhece todas as partículas de uma vez
+        for age in &mut self.ages {
+            *age += delta;
+        }
+
+        let mut i = 0;
+        while i < self.len() {
+            let mut particle = Particle::new(self.positions[i], self.velocities[i]);
+            let alive = self.ages[i] < self.lifetimes[i] && particle.update_with_walls(delta, walls);
+
+            if alive {
+                self.positions[i] = particle.position;
+                self.velocities[i] = particle.speed;
+                i += 1;
+            } else {
+                // A última partícula vem pro índice i e é processada na próxima iteração
+                self.kill(i);
+            }
+        }
+    }
+
+    /// Mesmo que `update`, mas move as partículas em paralelo (usando Rayon).
+    /// As partículas mortas são removidas depois, em sequência, então o resultado
+    /// não depende do número de threads.
+    pub fn par_update(&mut self, delta: f64, walls: &[Wall]) {
+        let alive: Vec<bool> = self
+            .positions
+            .par_iter_mut()
+            .zip(self.velocities.par_iter_mut())
+            .zip(self.ages.par_iter_mut().zip(self.lifetimes.par_iter()))
+            .map(|((position, velocity), (age, lifetime))| {
+                *age += delta;
+                let mut particle = Particle::new(*position, *velocity);
+                let alive = *age < *lifetime && particle.update_with_walls(delta, walls);
+                *position = particle.position;
+                *velocity = particle.speed;
+                alive
+            })
+            .collect();
+
+        // Remove de trás pra frente: a partícula trazida pelo swap-remove já foi checada
+        for i in (0..alive.len()).rev() {
+            if !alive[i] {
+                self.kill(i);
+            }
+        }
+    }
+
+    #[inline]
+    /// Desenha todas as partículas
+    pub fn draw(&self, color: Color) {
+        for p in &self.positions {
+            draw_circle(p.x as f32, p.y as f32, 3.0, color);
+        }
+    }
+}
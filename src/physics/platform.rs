@@ -0,0 +1,210 @@
+use macroquad::color::Color;
+
+use crate::{
+    algebra::Vec2,
+    physics::{
+        body::RigidBody,
+        shapes::{Collider, Line, Material, Particle, Polygon, Wall, collides},
+    },
+};
+
+/// Como uma plataforma percorre o seu caminho
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PathMode {
+    /// Volta do último ponto para o primeiro
+    Loop,
+    /// Vai e volta pelo caminho
+    PingPong,
+    /// Para no último ponto
+    Once,
+}
+
+/// Plataforma cinemática (não sofre forças). Pode ser de mão única e pode se mover
+/// por um caminho, carregando as partículas, corpos e personagens apoiados nela.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Platform {
+    /// Forma da plataforma (polígono convexo; dois vértices formam uma linha)
+    pub polygon: Polygon,
+    /// Direção "pra cima" da plataforma: o lado em que as coisas se apoiam
+    pub up: Vec2,
+    /// Se a plataforma só bloqueia o que vem do lado de `up` (atravessável por baixo)
+    pub one_way: bool,
+    /// Material das bordas para as partículas
+    pub material: Material,
+    /// Pontos por onde o centroide da plataforma passa
+    pub path: Vec<Vec2>,
+    /// Velocidade ao longo do caminho
+    pub speed: f64,
+    pub mode: PathMode,
+    /// Distância máxima de `up` em que algo conta como apoiado na plataforma
+    pub ride_distance: f64,
+    target: usize,
+    forward: bool,
+    displacement: Vec2,
+}
+
+impl Platform {
+    #[must_use]
+    /// Plataforma sólida parada
+    pub fn new(polygon: Polygon) -> Platform {
+        Platform {
+            polygon,
+            up: -Vec2::Y,
+            one_way: false,
+            material: Material::ELASTIC,
+            path: Vec::new(),
+            speed: 0.0,
+            mode: PathMode::Loop,
+            ride_distance: 1.0,
+            target: 0,
+            forward: true,
+            displacement: Vec2::NULL,
+        }
+    }
+
+    #[must_use]
+    /// Plataforma de mão única feita de uma linha. O lado sólido é o da normal da linha.
+    pub fn one_way(line: Line) -> Platform {
+        Platform {
+            up: line.normal(),
+            one_way: true,
+            ..Platform::new(Polygon::new(vec![line.p1, line.p2]))
+        }
+    }
+
+    #[must_use]
+    /// Plataforma sólida que se move pelos pontos de um caminho
+    pub fn moving(polygon: Polygon, path: Vec<Vec2>, speed: f64, mode: PathMode) -> Platform {
+        Platform {
+            path,
+            speed,
+            mode,
+            ..Platform::new(polygon)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o deslocamento da plataforma no último `update`
+    pub fn displacement(&self) -> Vec2 {
+        self.displacement
+    }
+
+    /// Move a plataforma pelo caminho durante `delta` segundos.
+    /// Retorna o deslocamento feito.
+    pub fn update(&mut self, delta: f64) -> Vec2 {
+        let start = self.polygon.centroid();
+        let mut position = start;
+        let mut step = self.speed * delta;
+
+        while step > 0.0 && !self.path.is_empty() {
+            let to_target = self.path[self.target] - position;
+            let distance = to_target.length();
+            if distance > step {
+                position += to_target * (step / distance);
+                break;
+            }
+            position = self.path[self.target];
+            step -= distance;
+            if !self.advance() {
+                break;
+            }
+        }
+
+        self.polygon.set_center(position);
+        self.displacement = position - start;
+        self.displacement
+    }
+
+    #[must_use]
+    /// Retorna as bordas da plataforma como paredes para partículas.
+    /// Nas plataformas de mão única, só as bordas viradas para `up` contam.
+    pub fn walls(&self) -> Vec<Wall> {
+        let edges = self.polygon.edges().into_iter();
+        if !self.one_way {
+            return edges.map(|edge| Wall::new(edge, self.material)).collect();
+        }
+        // Orienta as bordas para que as normais apontem para fora do polígono
+        let clockwise = self.polygon.signed_area() < 0.0;
+        edges
+            .map(|edge| {
+                if clockwise {
+                    Line::new(edge.p2, edge.p1)
+                } else {
+                    edge
+                }
+            })
+            .filter(|edge| !edge.is_degenerate() && edge.normal().dot(self.up) > 0.0)
+            .map(|edge| Wall::one_way(edge, self.material))
+            .collect()
+    }
+
+    #[must_use]
+    /// Checa se um ponto estava apoiado na plataforma antes do último deslocamento
+    pub fn supports_point(&self, point: Vec2) -> bool {
+        let point = point + self.displacement;
+        let closest = self.polygon.closest_point(point);
+        let offset = point - closest;
+        !self.polygon.contains_point(point)
+            && offset.length() <= self.ride_distance
+            && offset.dot(self.up) >= 0.0
+    }
+
+    #[must_use]
+    /// Checa se um collider estava apoiado na plataforma antes do último deslocamento
+    pub fn supports(&self, collider: &dyn Collider) -> bool {
+        let mut previous = self.polygon.clone();
+        previous
+            .set_center(self.polygon.centroid() - self.displacement + self.up * self.ride_distance);
+        (collider.center() - previous.centroid()).dot(self.up) > 0.0
+            && collides(&previous, collider)
+    }
+
+    /// Move junto com a plataforma as partículas apoiadas nela
+    pub fn carry_particles(&self, particles: &mut [Particle]) {
+        for particle in particles {
+            if self.supports_point(particle.position) {
+                particle.position += self.displacement;
+            }
+        }
+    }
+
+    /// Move junto com a plataforma um corpo apoiado nela
+    pub fn carry<C: Collider>(&self, body: &mut RigidBody<C>) {
+        if self.supports(&body.collider) {
+            let position = body.position() + self.displacement;
+            body.collider.set_center(position);
+        }
+    }
+
+    #[inline]
+    /// Desenha o contorno da plataforma
+    pub fn draw(&self, thickness: f32, color: Color) {
+        self.polygon.draw(thickness, color);
+    }
+
+    /// (private) Passa para o próximo ponto do caminho.
+    /// Retorna `false` se a plataforma deve parar.
+    fn advance(&mut self) -> bool {
+        let n = self.path.len();
+        if n < 2 {
+            return false;
+        }
+        match self.mode {
+            PathMode::Loop => self.target = (self.target + 1) % n,
+            PathMode::Once if self.target + 1 < n => self.target += 1,
+            PathMode::Once => return false,
+            PathMode::PingPong => {
+                if (self.forward && self.target + 1 == n) || (!self.forward && self.target == 0) {
+                    self.forward = !self.forward;
+                }
+                if self.forward {
+                    self.target += 1;
+                } else {
+                    self.target -= 1;
+                }
+            }
+        }
+        true
+    }
+}
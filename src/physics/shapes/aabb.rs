@@ -1,23 +1,21 @@
-use std::f64::INFINITY;
-
 use macroquad::{color::Color, shapes::draw_rectangle_lines};
 
 use crate::{
-    algebra::Vec2,
-    physics::shapes::{Circle, Collider, Line, OBB},
+    algebra::{Scalar, Vec2},
+    physics::shapes::{Circle, Collider, Line, OBB, collider::closest_point_on_edges},
 };
 
 #[derive(Clone, Copy, PartialEq)]
-pub struct AABB {
-    pub min: Vec2,
-    pub max: Vec2,
+pub struct AABB<T = f64> {
+    pub min: Vec2<T>,
+    pub max: Vec2<T>,
 }
 
-impl AABB {
+impl<T: Scalar> AABB<T> {
     #[must_use]
     /// Cria uma AABB com dois pontos como limites. Esta função independe da ordem
     /// dos pontos e constrói uma AABB com min = (min_x,min_y) e max = (max_x,max_y)
-    pub fn new(p1: Vec2, p2: Vec2) -> AABB {
+    pub fn new(p1: Vec2<T>, p2: Vec2<T>) -> AABB<T> {
         let (min_x, min_y) = (p1.x.min(p2.x), p1.y.min(p2.y));
         let (max_x, max_y) = (p1.x.max(p2.x), p1.y.max(p2.y));
         AABB {
@@ -28,10 +26,10 @@ impl AABB {
 
     #[must_use]
     /// Retorna uma AABB que contém todos os pontos de um vetor. Pânico se points.len() == 0
-    pub fn enclosing(points: &Vec<Vec2>) -> AABB {
+    pub fn enclosing(points: &Vec<Vec2<T>>) -> AABB<T> {
         assert!(points.len() > 0, "Número de pontos deve ser maior que 0!");
-        let (mut min_x, mut min_y) = (INFINITY, INFINITY);
-        let (mut max_x, mut max_y) = (-INFINITY, -INFINITY);
+        let (mut min_x, mut min_y) = (T::INFINITY, T::INFINITY);
+        let (mut max_x, mut max_y) = (-T::INFINITY, -T::INFINITY);
 
         for p in points {
             min_x = min_x.min(p.x);
@@ -49,14 +47,14 @@ impl AABB {
     #[inline]
     #[must_use]
     /// Retorna a largura da AABB
-    pub fn width(&self) -> f64 {
+    pub fn width(&self) -> T {
         self.max.x - self.min.x
     }
 
     #[inline]
     #[must_use]
     /// Retorna a altura da AABB
-    pub fn height(&self) -> f64 {
+    pub fn height(&self) -> T {
         self.max.y - self.min.y
     }
 
@@ -64,10 +62,10 @@ impl AABB {
     /// Desenha o frame da AABB na tela
     pub fn draw(&self, thickness: f32, color: Color) {
         draw_rectangle_lines(
-            self.min.x as f32,
-            self.min.y as f32,
-            self.width() as f32,
-            self.height() as f32,
+            self.min.x.to_f32(),
+            self.min.y.to_f32(),
+            self.width().to_f32(),
+            self.height().to_f32(),
             thickness,
             color,
         );
@@ -76,15 +74,15 @@ impl AABB {
     #[inline]
     #[must_use]
     /// Checa se um ponto está dentro da bounding box
-    pub fn contains_point(&self, point: Vec2) -> bool {
+    pub fn contains_point(&self, point: Vec2<T>) -> bool {
         point.x > self.min.x && point.x < self.max.x && point.y > self.min.y && point.y < self.max.y
     }
 }
 
-impl Collider for AABB {
-    fn grow(&mut self, width: f64, height: f64) {
-        let half_w = width / 2.0;
-        let half_h = height / 2.0;
+impl<T: Scalar> Collider<T> for AABB<T> {
+    fn grow(&mut self, width: T, height: T) {
+        let half_w = width / T::from_f64(2.0);
+        let half_h = height / T::from_f64(2.0);
 
         let new_min = Vec2::new(self.min.x - half_w, self.min.y - half_h);
         let new_max = Vec2::new(self.max.x + half_w, self.max.y + half_h);
@@ -95,23 +93,23 @@ impl Collider for AABB {
         }
     }
 
-    fn rotate(&mut self, _theta: f64) {}
+    fn rotate(&mut self, _theta: T) {}
 
-    fn set_center(&mut self, pos: Vec2) {
+    fn set_center(&mut self, pos: Vec2<T>) {
         let offset = pos - self.center();
         self.max += offset;
         self.min += offset;
     }
 
-    fn size(&self) -> Vec2 {
+    fn size(&self) -> Vec2<T> {
         self.max - self.min
     }
 
-    fn center(&self) -> Vec2 {
-        (self.min + self.max) / 2.0
+    fn center(&self) -> Vec2<T> {
+        (self.min + self.max) / T::from_f64(2.0)
     }
 
-    fn edges(&self) -> Vec<Line> {
+    fn edges(&self) -> Vec<Line<T>> {
         let (e1, e2) = (self.min, self.min + Vec2::X * self.width());
         let (e3, e4) = (self.max, self.max - Vec2::X * self.width());
         vec![
@@ -126,8 +124,16 @@ impl Collider for AABB {
         self.draw(thickness, color);
     }
 
-    fn project(&self, axis: Vec2) -> (f64, f64) {
-        let extents = (self.max - self.min) / 2.0;
+    fn contains_point(&self, point: Vec2<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    fn closest_point(&self, point: Vec2<T>) -> Vec2<T> {
+        closest_point_on_edges(self.edges(), point)
+    }
+
+    fn project(&self, axis: Vec2<T>) -> (T, T) {
+        let extents = (self.max - self.min) / T::from_f64(2.0);
         // Projeção do centro da caixa sobre o eixo
         let center_p = self.center().dot(axis);
         // Projeção da metade da caixa sobre o eixo
@@ -136,7 +142,7 @@ impl Collider for AABB {
         (center_p - extents_p, center_p + extents_p)
     }
 
-    fn sat_axes(&self, _other: &dyn Collider) -> Vec<Vec2> {
+    fn sat_axes(&self, _other: &dyn Collider<T>) -> Vec<Vec2<T>> {
         vec![Vec2::X, Vec2::Y]
     }
 }
@@ -1,21 +1,21 @@
 use macroquad::{color::Color, shapes::draw_circle_lines};
 
 use crate::{
-    algebra::Vec2,
+    algebra::{Scalar, Vec2},
     physics::shapes::{AABB, Collider, Line, OBB},
 };
 
 #[derive(Clone, Copy, PartialEq)]
-pub struct Circle {
-    pub center: Vec2,
-    pub radius: f64,
+pub struct Circle<T = f64> {
+    pub center: Vec2<T>,
+    pub radius: T,
 }
 
-impl Circle {
+impl<T: Scalar> Circle<T> {
     #[inline]
     #[must_use]
     /// Gera um círculo com centro e raio definidos.
-    pub fn new(center: Vec2, radius: f64) -> Circle {
+    pub fn new(center: Vec2<T>, radius: T) -> Circle<T> {
         Circle { center, radius }
     }
 
@@ -24,10 +24,10 @@ impl Circle {
     /// Faz isso gerando um círculo no centro de uma AABB e usando a maior
     /// distância do centro até um ponto do vetor como raio.
     /// Pânico se points.len() == 0
-    pub fn enclosing(points: &Vec<Vec2>) -> Circle {
+    pub fn enclosing(points: &Vec<Vec2<T>>) -> Circle<T> {
         assert!(points.len() > 0, "Número de pontos deve ser maior que 0!");
         let aabb = AABB::enclosing(points);
-        let center = (aabb.max + aabb.min) / 2.0;
+        let center = (aabb.max + aabb.min) / T::from_f64(2.0);
         let radius = points
             .iter()
             .map(|p| p.distance_to_squared(center))
@@ -41,9 +41,9 @@ impl Circle {
     /// Desenha o frame da AABB na tela
     pub fn draw(&self, thickness: f32, color: Color) {
         draw_circle_lines(
-            self.center.x as f32,
-            self.center.y as f32,
-            self.radius as f32,
+            self.center.x.to_f32(),
+            self.center.y.to_f32(),
+            self.radius.to_f32(),
             thickness,
             color,
         );
@@ -52,22 +52,22 @@ impl Circle {
     #[inline]
     #[must_use]
     /// Checa se se um ponto está dentro do círculo.
-    pub fn contains_point(&self, point: Vec2) -> bool {
+    pub fn contains_point(&self, point: Vec2<T>) -> bool {
         point.distance_to_squared(self.center) < self.radius * self.radius
     }
 
     /// Retorna o ponto mais próximo do círculo em uma linha
-    pub fn closest_point_on_line(&self, line: Line) -> Vec2 {
+    pub fn closest_point_on_line(&self, line: Line<T>) -> Vec2<T> {
         let line_dr = line.p2 - line.p1;
         let ac = self.center - line.p1;
-        let t = (ac.dot(line_dr) / line_dr.length_squared()).clamp(0.0, 1.0);
+        let t = (ac.dot(line_dr) / line_dr.length_squared()).clamp(T::ZERO, T::ONE);
         line.p1 + line_dr * t
     }
 
     #[inline]
     /// (private) Retorna o eixo entre o centro do círculo e um ponto
     /// (Vetor vazio se os pontos forem iguais. Evita vetor degenerado.)
-    fn sanitized_axis(&self, point: Vec2) -> Vec<Vec2> {
+    fn sanitized_axis(&self, point: Vec2<T>) -> Vec<Vec2<T>> {
         if self.center.is_same(point) {
             vec![]
         } else {
@@ -76,26 +76,26 @@ impl Circle {
     }
 }
 
-impl Collider for Circle {
-    fn grow(&mut self, width: f64, height: f64) {
+impl<T: Scalar> Collider<T> for Circle<T> {
+    fn grow(&mut self, width: T, height: T) {
         if width.abs() > height.abs() {
             self.radius += width
         }
         self.radius += height;
-        self.radius = self.radius.max(0.0)
+        self.radius = self.radius.max(T::ZERO)
     }
 
-    fn rotate(&mut self, _theta: f64) {}
+    fn rotate(&mut self, _theta: T) {}
 
-    fn set_center(&mut self, pos: Vec2) {
+    fn set_center(&mut self, pos: Vec2<T>) {
         self.center = pos
     }
 
-    fn size(&self) -> Vec2 {
+    fn size(&self) -> Vec2<T> {
         Vec2::new(self.radius, self.radius)
     }
 
-    fn sat_axes(&self, other: &dyn Collider) -> Vec<Vec2> {
+    fn sat_axes(&self, other: &dyn Collider<T>) -> Vec<Vec2<T>> {
         let edges = other.edges();
         // Caso 1: Círculo -> Eixo entre os dois centros
         if edges.is_empty() {
@@ -115,11 +115,11 @@ impl Collider for Circle {
         }
     }
 
-    fn edges(&self) -> Vec<super::Line> {
+    fn edges(&self) -> Vec<Line<T>> {
         vec![]
     }
 
-    fn center(&self) -> Vec2 {
+    fn center(&self) -> Vec2<T> {
         self.center
     }
 
@@ -127,7 +127,21 @@ impl Collider for Circle {
         self.draw(thickness, color);
     }
 
-    fn project(&self, axis: Vec2) -> (f64, f64) {
+    fn contains_point(&self, point: Vec2<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    fn closest_point(&self, point: Vec2<T>) -> Vec2<T> {
+        // Ponto no centro: qualquer direção serve
+        let direction = if self.center.is_same(point) {
+            Vec2::X
+        } else {
+            (point - self.center).normalized()
+        };
+        self.center + direction * self.radius
+    }
+
+    fn project(&self, axis: Vec2<T>) -> (T, T) {
         let c = self.center.dot(axis); // projeção do centro pro eixo
         (c - self.radius, c + self.radius)
     }
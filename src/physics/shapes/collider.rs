@@ -1,45 +1,52 @@
 use macroquad::color::Color;
 
 use crate::{
-    algebra::Vec2,
+    algebra::{Scalar, Vec2},
     physics::shapes::{AABB, Circle, Line, OBB},
 };
 
 /// Um trait que engloba objetos em geral e lida com suas colisões.
-pub trait Collider {
+/// Genérico no tipo dos números (f64 por padrão).
+pub trait Collider<T: Scalar = f64> {
     /// Move o centro do objeto para uma posição específica
-    fn set_center(&mut self, pos: Vec2);
+    fn set_center(&mut self, pos: Vec2<T>);
 
     /// Rotaciona o objeto por um ângulo theta (só funciona para OBB no código atual)
-    fn rotate(&mut self, theta: f64);
+    fn rotate(&mut self, theta: T);
 
     /// Aumenta/diminui o tamanho do objeto
-    fn grow(&mut self, width: f64, height: f64);
+    fn grow(&mut self, width: T, height: T);
 
     /// Retorna a largura e altura do objeto
-    fn size(&self) -> Vec2;
+    fn size(&self) -> Vec2<T>;
 
     /// Desenha o objeto
     fn draw(&self, thickness: f32, color: Color);
 
     /// Retorna o centro do objeto
-    fn center(&self) -> Vec2;
+    fn center(&self) -> Vec2<T>;
+
+    /// Checa se um ponto está dentro do objeto
+    fn contains_point(&self, point: Vec2<T>) -> bool;
+
+    /// Retorna o ponto da borda do objeto mais próximo de um ponto
+    fn closest_point(&self, point: Vec2<T>) -> Vec2<T>;
 
     /// Retorna os valores min,max da projeção do objeto sobre um eixo
-    fn project(&self, axis: Vec2) -> (f64, f64);
+    fn project(&self, axis: Vec2<T>) -> (T, T);
 
     /// Retorna todas as bordas do objeto
-    fn edges(&self) -> Vec<Line>;
+    fn edges(&self) -> Vec<Line<T>>;
 
     /// Retorna os eixos com que o objeto contribui para o cálculo.
     /// (O "other" é necessário para definir o eixo do círculo:
     /// o único eixo que importa seria o eixo do centro do círculo
     /// pro ponto mais próximo entre o círculo.)
-    fn sat_axes(&self, other: &dyn Collider) -> Vec<Vec2>;
+    fn sat_axes(&self, other: &dyn Collider<T>) -> Vec<Vec2<T>>;
 }
 
 /// Checa se um objeto colide com o outro usando SAT
-pub fn collides(a: &dyn Collider, b: &dyn Collider) -> bool {
+pub fn collides<T: Scalar>(a: &dyn Collider<T>, b: &dyn Collider<T>) -> bool {
     // Obtém os eixos necessários para o teste
     let ax = a.sat_axes(b);
     let bx = b.sat_axes(a);
@@ -56,3 +63,15 @@ pub fn collides(a: &dyn Collider, b: &dyn Collider) -> bool {
         max_a < min_b || max_b < min_a
     })
 }
+
+/// (private) Ponto mais próximo de `point` em um conjunto de bordas
+pub(crate) fn closest_point_on_edges<T: Scalar>(edges: Vec<Line<T>>, point: Vec2<T>) -> Vec2<T> {
+    edges
+        .into_iter()
+        .map(|edge| edge.closest_point(point))
+        .min_by(|a, b| {
+            a.distance_to_squared(point)
+                .total_cmp(&b.distance_to_squared(point))
+        })
+        .unwrap_or(point)
+}
@@ -1,29 +1,47 @@
-use crate::{algebra::Vec2, physics::Intersection};
+use crate::{
+    algebra::{Orientation, Scalar, Vec2},
+    physics::Intersection,
+};
 use macroquad::{color::Color, shapes::draw_line};
 
+/// Threshold para retas paralelas, aplicado ao seno do ângulo entre elas
+/// (relativo ao tamanho das retas, funciona em qualquer escala de coordenadas)
+const PARALLEL_EPS: f64 = 1e-8;
+
 /// Struct para uma linha em um espaço 2D
-#[derive(Clone, Copy, PartialEq)]
-pub struct Line {
-    pub p1: Vec2,
-    pub p2: Vec2,
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Line<T = f64> {
+    pub p1: Vec2<T>,
+    pub p2: Vec2<T>,
+}
+
+/// Classificação da interseção entre dois segmentos
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SegmentIntersection<T = f64> {
+    /// Os segmentos não se tocam
+    None,
+    /// Os segmentos se tocam em um único ponto (no meio ou em uma das pontas)
+    Point(Vec2<T>),
+    /// Os segmentos são colineares e se sobrepõem nesse trecho
+    Overlap(Line<T>),
 }
 
 // Métodos
-impl Line {
+impl<T: Scalar> Line<T> {
     #[inline]
     #[must_use]
     /// Construtor da linha
-    pub fn new(p1: Vec2, p2: Vec2) -> Line {
+    pub fn new(p1: Vec2<T>, p2: Vec2<T>) -> Line<T> {
         return Line { p1, p2 };
     }
 
     /// Desenha a linha na tela
     pub fn draw(&self, thickness: f32, color: Color) {
         draw_line(
-            self.p1.x as f32,
-            self.p1.y as f32,
-            self.p2.x as f32,
-            self.p2.y as f32,
+            self.p1.x.to_f32(),
+            self.p1.y.to_f32(),
+            self.p2.x.to_f32(),
+            self.p2.y.to_f32(),
             thickness,
             color,
         );
@@ -31,7 +49,7 @@ impl Line {
 
     #[must_use]
     /// Retorna um vetor normal à linha p1 - p2 (-90°)
-    pub fn normal(&self) -> Vec2 {
+    pub fn normal(&self) -> Vec2<T> {
         let v = self.p2 - self.p1; // vetor de p1 pra p2
         let n = Vec2::new(v.y, -v.x);
         return n.normalized();
@@ -40,14 +58,14 @@ impl Line {
     #[inline]
     #[must_use]
     /// Retorna o tamanho da reta ao quadrado (mais rápido que length() * length())
-    pub fn length_squared(&self) -> f64 {
+    pub fn length_squared(&self) -> T {
         return (self.p1 - self.p2).length_squared();
     }
 
     #[inline]
     #[must_use]
     /// Retorna o tamanho da reta
-    pub fn length(&self) -> f64 {
+    pub fn length(&self) -> T {
         return (self.p1 - self.p2).length();
     }
 
@@ -56,61 +74,119 @@ impl Line {
     /// Retorna se a reta é degenerada ou não testando se
     /// a distância entre seus dois pontos é igual a 0 (threshold 1e-6)
     pub fn is_degenerate(&self) -> bool {
-        return self.length_squared() <= 1e-12;
+        return self.length_squared() <= T::from_f64(1e-12);
     }
 
     #[must_use]
     /// Checa se uma reta é paralela com a outra através do produto vetorial.
-    /// Se o produto vetorial der igual a 0, elas são paralalas. (Threshold 1e-8)
-    pub fn is_parallel_with(&self, other: Line) -> bool {
+    /// Se o produto vetorial der igual a 0, elas são paralalas.
+    /// (Threshold 1e-8 no seno do ângulo entre elas)
+    pub fn is_parallel_with(&self, other: Line<T>) -> bool {
         let v1 = self.p2 - self.p1;
         let v2 = other.p2 - other.p1;
-        return v1.cross(v2).abs() <= 1e-8;
+        let scale = v1.length() * v2.length();
+        return v1.cross(v2).abs() <= T::from_f64(PARALLEL_EPS) * scale;
+    }
+
+    #[must_use]
+    /// Retorna se uma reta cruza a outra (encostar só na ponta não conta).
+    /// Usa o predicado de orientação exato, então não depende da escala.
+    pub fn intersects(&self, other: Line<T>) -> bool {
+        let (a, b) = (self.p1, self.p2);
+        let (c, d) = (other.p1, other.p2);
+
+        // C e D precisam estar estritamente em lados opostos de AB
+        // e A e B estritamente em lados opostos de CD.
+        return opposite_sides(T::orientation(a, b, c), T::orientation(a, b, d))
+            && opposite_sides(T::orientation(c, d, a), T::orientation(c, d, b));
     }
 
     #[must_use]
-    /// Retorna se uma reta intersecta a outra
-    pub fn intersects(&self, other: Line) -> bool {
-        let ab = self.p2 - self.p1;
-        let ac = other.p1 - self.p1;
-        let ad = other.p2 - self.p1;
-        let s1 = ab.cross(ac);
-        let s2 = ab.cross(ad);
-
-        // Se s1 e s2 tem o mesmo sinal, C e D estão no mesmo lado de AB.
-        // Logo, não há interseção.
-        if s1 * s2 >= 0.0 {
-            return false;
+    /// Classifica a interseção entre os segmentos: nenhuma, um ponto (inclusive
+    /// encostando na ponta) ou um trecho em comum, se forem colineares.
+    /// As decisões usam o predicado de orientação exato; só a posição do ponto
+    /// de cruzamento (quando não é uma das pontas) é calculada com arredondamento.
+    pub fn segment_intersection(&self, other: Line<T>) -> SegmentIntersection<T> {
+        let (a, b) = (self.p1, self.p2);
+        let (c, d) = (other.p1, other.p2);
+        let (o1, o2) = (T::orientation(c, d, a), T::orientation(c, d, b));
+        let (o3, o4) = (T::orientation(a, b, c), T::orientation(a, b, d));
+
+        let orientations = [o1, o2, o3, o4];
+        if orientations.iter().all(|o| *o == Orientation::Collinear) {
+            return self.collinear_overlap(other);
+        }
+        // As duas pontas de um segmento do mesmo lado do outro: não se tocam
+        if (o1 == o2 && o1 != Orientation::Collinear) || (o3 == o4 && o3 != Orientation::Collinear)
+        {
+            return SegmentIntersection::None;
         }
+        // Uma ponta sobre a outra reta é o próprio ponto de interseção
+        let point = if o1 == Orientation::Collinear {
+            a
+        } else if o2 == Orientation::Collinear {
+            b
+        } else if o3 == Orientation::Collinear {
+            c
+        } else if o4 == Orientation::Collinear {
+            d
+        } else {
+            let r = b - a;
+            let t = (c - a).cross(d - c) / r.cross(d - c);
+            a + r * t.clamp(T::ZERO, T::ONE)
+        };
+        SegmentIntersection::Point(point)
+    }
 
-        let cd = other.p2 - other.p1;
-        let ca = self.p1 - other.p1;
-        let cb = self.p2 - other.p1;
-        let s3 = cd.cross(ca);
-        let s4 = cd.cross(cb);
+    /// (private) Trecho em comum entre dois segmentos colineares
+    fn collinear_overlap(&self, other: Line<T>) -> SegmentIntersection<T> {
+        // Pontos colineares ficam ordenados ao longo da reta comparando x e depois y
+        // (comparar coordenadas é exato e também funciona com retas verticais)
+        let less = |p: Vec2<T>, q: Vec2<T>| p.x < q.x || (p.x == q.x && p.y < q.y);
+        let sorted = |p: Vec2<T>, q: Vec2<T>| if less(q, p) { (q, p) } else { (p, q) };
+        let ((a_lo, a_hi), (c_lo, c_hi)) = (sorted(self.p1, self.p2), sorted(other.p1, other.p2));
 
-        // Se s3 e s4 não tem o mesmo sinal, A e B não estão no mesmo lado de CD.
-        // Logo, há interseção.
-        return s3 * s4 < 0.0;
+        let lo = if less(a_lo, c_lo) { c_lo } else { a_lo };
+        let hi = if less(c_hi, a_hi) { c_hi } else { a_hi };
+        if less(hi, lo) {
+            SegmentIntersection::None
+        } else if lo == hi {
+            SegmentIntersection::Point(lo)
+        } else {
+            SegmentIntersection::Overlap(Line::new(lo, hi))
+        }
     }
 
     #[must_use]
-    pub fn direction(&self) -> Vec2 {
+    /// Retorna o ponto da reta (segmento p1-p2) mais próximo de um ponto
+    pub fn closest_point(&self, point: Vec2<T>) -> Vec2<T> {
+        let dr = self.p2 - self.p1;
+        if self.is_degenerate() {
+            return self.p1;
+        }
+        let t = ((point - self.p1).dot(dr) / dr.length_squared()).clamp(T::ZERO, T::ONE);
+        self.p1 + dr * t
+    }
+
+    #[must_use]
+    pub fn direction(&self) -> Vec2<T> {
         (self.p2 - self.p1).normalized()
     }
 
     #[must_use]
     /// Retorna a interseção entre duas retas (None se não há interseção).
     /// Utiliza a equação de interseção entre reta e hiperplano.
-    pub fn intersection(&self, other: Line) -> Option<Intersection> {
+    pub fn intersection(&self, other: Line<T>) -> Option<Intersection<T>> {
         let r = self.p2 - self.p1;
         let s = other.p2 - other.p1;
         let n = Vec2::new(-s.y, s.x);
 
         // Se o produto escalar entre a reta e a normal da outra reta é igual a 0,
         // isso quer dizer que elas são paralelas e não há colisão.
+        // (threshold no seno do ângulo, relativo ao tamanho das retas)
         let bottom = r.dot(n);
-        if bottom.abs() < 1e-8 {
+        let scale = r.length() * s.length();
+        if bottom.abs() <= T::from_f64(PARALLEL_EPS) * scale {
             return None;
         }
 
@@ -127,3 +203,121 @@ impl Line {
         })
     }
 }
+
+/// (private) Checa se duas orientações indicam lados estritamente opostos
+fn opposite_sides(o1: Orientation, o2: Orientation) -> bool {
+    matches!(
+        (o1, o2),
+        (Orientation::Clockwise, Orientation::CounterClockwise)
+            | (Orientation::CounterClockwise, Orientation::Clockwise)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Line {
+        Line::new(Vec2::new(x1, y1), Vec2::new(x2, y2))
+    }
+
+    #[test]
+    fn collinear_overlap() {
+        // Sobreposição parcial, em qualquer ordem das pontas
+        let expected = SegmentIntersection::Overlap(line(2.0, 1.0, 4.0, 2.0));
+        assert_eq!(
+            line(0.0, 0.0, 4.0, 2.0).segment_intersection(line(2.0, 1.0, 6.0, 3.0)),
+            expected
+        );
+        assert_eq!(
+            line(4.0, 2.0, 0.0, 0.0).segment_intersection(line(6.0, 3.0, 2.0, 1.0)),
+            expected
+        );
+        // Um segmento contido no outro
+        assert_eq!(
+            line(0.0, 0.0, 6.0, 3.0).segment_intersection(line(4.0, 2.0, 2.0, 1.0)),
+            expected
+        );
+        // Retas verticais (ordenadas pelo y)
+        assert_eq!(
+            line(1.0, 5.0, 1.0, 0.0).segment_intersection(line(1.0, 3.0, 1.0, 8.0)),
+            SegmentIntersection::Overlap(line(1.0, 3.0, 1.0, 5.0))
+        );
+        // Colineares sem sobreposição
+        assert_eq!(
+            line(0.0, 0.0, 1.0, 1.0).segment_intersection(line(2.0, 2.0, 3.0, 3.0)),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn collinear_overlap_at_large_coordinates() {
+        let offset = 1e6 + 0.1;
+        let a = line(offset, offset, offset + 3.0, offset + 3.0);
+        let b = line(offset + 1.0, offset + 1.0, offset + 5.0, offset + 5.0);
+        assert_eq!(
+            a.segment_intersection(b),
+            SegmentIntersection::Overlap(Line::new(b.p1, a.p2))
+        );
+    }
+
+    #[test]
+    fn shared_endpoints() {
+        // Colineares encostando só na ponta
+        assert_eq!(
+            line(0.0, 0.0, 2.0, 0.0).segment_intersection(line(2.0, 0.0, 5.0, 0.0)),
+            SegmentIntersection::Point(Vec2::new(2.0, 0.0))
+        );
+        // Em "L": a ponta comum é devolvida exatamente
+        let corner = Vec2::new(0.1, 0.7);
+        let a = Line::new(Vec2::new(-3.3, 2.9), corner);
+        let b = Line::new(corner, Vec2::new(5.1, -1.3));
+        assert_eq!(
+            a.segment_intersection(b),
+            SegmentIntersection::Point(corner)
+        );
+        assert_eq!(
+            b.segment_intersection(a),
+            SegmentIntersection::Point(corner)
+        );
+        // Em "T": a ponta de um segmento no meio do outro
+        assert_eq!(
+            line(0.0, 0.0, 4.0, 0.0).segment_intersection(line(1.0, 0.0, 1.0, 3.0)),
+            SegmentIntersection::Point(Vec2::new(1.0, 0.0))
+        );
+        // Cruzamento comum e segmentos separados
+        assert_eq!(
+            line(0.0, 0.0, 2.0, 2.0).segment_intersection(line(0.0, 2.0, 2.0, 0.0)),
+            SegmentIntersection::Point(Vec2::new(1.0, 1.0))
+        );
+        assert_eq!(
+            line(0.0, 0.0, 1.0, 0.0).segment_intersection(line(2.0, -1.0, 2.0, 1.0)),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn parallel_threshold_does_not_overflow_or_underflow() {
+        use crate::algebra::Fixed;
+
+        // Parede de 1000 unidades e passo de 50 quase paralelo (seno ~9.6e-9) em ponto
+        // fixo: |r|² · |s|² passa do limite do `Fixed` e o threshold ficaria menor
+        let wall = Line::new(
+            Vec2::<Fixed>::NULL,
+            Vec2::new(Fixed::from_int(1000), Fixed::ZERO),
+        );
+        let step = Line::new(
+            Vec2::NULL,
+            Vec2::new(Fixed::from_int(50), Fixed::from_f64(4.8e-7)),
+        );
+        assert!(step.is_parallel_with(wall));
+        assert!(step.intersection(wall).is_none());
+
+        // Vetores minúsculos em f64: o produto dos quadrados vira 0 e nenhuma
+        // reta quase paralela seria detectada
+        let (r, s) = (line(0.0, 0.0, 1e-100, 0.0), line(0.0, 0.0, 1e-100, 1e-109));
+        assert!(r.is_parallel_with(s));
+        assert!(r.intersection(s).is_none());
+        assert!(!r.is_parallel_with(line(0.0, 0.0, 0.0, 1e-100)));
+    }
+}
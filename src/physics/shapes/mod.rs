@@ -6,10 +6,17 @@ mod collider;
 mod line;
 mod obb;
 mod particle;
+mod polygon;
+mod transform;
+mod wall;
 pub use aabb::AABB;
 pub use circle::Circle;
 pub use collider::Collider;
-pub use line::Line;
+pub use line::{Line, SegmentIntersection};
 pub use obb::OBB;
 pub use particle::Particle;
+pub use polygon::Polygon;
+pub use transform::{AffineTransform, Local, RigidTransform, TransformedShape};
+pub use wall::{HitCallback, HitResponse, Material, Wall};
 pub use collider::collides;
+pub(crate) use particle::{SURFACE_EPS, closest_hit};
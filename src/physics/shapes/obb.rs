@@ -1,26 +1,25 @@
 use rayon::prelude::*;
-use std::f64::{INFINITY, consts::PI};
 
 use macroquad::{color::Color, shapes::draw_line};
 
 use crate::{
-    algebra::Vec2,
-    physics::shapes::{AABB, Circle, Collider, Line},
+    algebra::{Isometry2, Rot2, Scalar, Vec2, Vec2Batch, batch},
+    physics::shapes::{AABB, Circle, Collider, Line, collider::closest_point_on_edges},
 };
 
 #[derive(Clone, Copy, PartialEq)]
-pub struct OBB {
-    pub center: Vec2,
-    pub extents: Vec2,
-    pub u: Vec2,
-    pub v: Vec2,
+pub struct OBB<T = f64> {
+    pub center: Vec2<T>,
+    pub extents: Vec2<T>,
+    pub u: Vec2<T>,
+    pub v: Vec2<T>,
 }
 
-impl OBB {
+impl<T: Scalar> OBB<T> {
     #[inline]
     #[must_use]
     /// Construtor genérico de OBB.
-    pub fn new(center: Vec2, extents: Vec2, u: Vec2, v: Vec2) -> OBB {
+    pub fn new(center: Vec2<T>, extents: Vec2<T>, u: Vec2<T>, v: Vec2<T>) -> OBB<T> {
         OBB {
             center,
             extents,
@@ -32,24 +31,27 @@ impl OBB {
     /// Cria uma OBB que engloba todos os pontos de um vetor.
     /// Usa um algoritmo "força bruta" para gerar uma OBB ótima.
     /// Pânico se points.len() == 0
-    pub fn enclosing(points: &Vec<Vec2>) -> OBB {
+    pub fn enclosing(points: &[Vec2<T>]) -> OBB<T> {
         assert!(points.len() > 0, "Número de pontos deve ser maior que 0!");
+        // Os pontos são projetados 360 vezes: o formato SoA deixa as projeções vetorizadas
+        let batch = Vec2Batch::from_points(points);
         // Testa os 180 os ângulos entre -90 e 89 para ver qual a melhor bounding box (força bruta)
         (-90..90)
             .par_bridge() // Faz as computações em paralelo usando a biblioteca Rayon
-            .map(|t| OBB::from_angle_enclosing(points, (t as f64).to_radians()))
+            .map(|t| OBB::from_batch_enclosing(&batch, T::from_f64((t as f64).to_radians())))
             .min_by(|a, b| a.area().total_cmp(&b.area()))
             .unwrap()
     }
 
     /// Retorna a área da OBB
-    pub fn area(&self) -> f64 {
-        (self.extents.x * 2.0) * (self.extents.y * 2.0)
+    pub fn area(&self) -> T {
+        let two = T::from_f64(2.0);
+        (self.extents.x * two) * (self.extents.y * two)
     }
 
     /// Função para criar uma OBB que engloba pontos com eixo U
     /// definido por um certo ângulo
-    pub fn from_angle(center: Vec2, extents: Vec2, theta: f64) -> OBB {
+    pub fn from_angle(center: Vec2<T>, extents: Vec2<T>, theta: T) -> OBB<T> {
         // Cria um vetor U baseado em um ângulo específico
         let u = Vec2::from_angle(theta).normalized();
         let v = Vec2::new(-u.y, u.x).normalized();
@@ -58,23 +60,36 @@ impl OBB {
 
     /// Função para criar uma OBB que engloba pontos com eixo U
     /// definido por um certo ângulo
-    pub fn from_angle_enclosing(points: &Vec<Vec2>, theta: f64) -> OBB {
+    pub fn from_angle_enclosing(points: &[Vec2<T>], theta: T) -> OBB<T> {
         // Cria um vetor U baseado em um ângulo específico
         let u = Vec2::from_angle(theta).normalized();
         let v = Vec2::new(-u.y, u.x).normalized();
 
         // Obtém os extents de acordo com a projeção dos pontos nos eixos
-        let (min_u, max_u) = minmax_projection(points, u);
-        let (min_v, max_v) = minmax_projection(points, v);
-        let extents = Vec2::new(max_u - min_u, max_v - min_v) / 2.0;
+        let u_range = batch::minmax_projection(points, u);
+        let v_range = batch::minmax_projection(points, v);
+        OBB::from_projections(u, v, u_range, v_range)
+    }
+
+    /// (private) Igual a `from_angle_enclosing`, mas com os pontos já em formato SoA
+    fn from_batch_enclosing(batch: &Vec2Batch<T>, theta: T) -> OBB<T> {
+        let u = Vec2::from_angle(theta).normalized();
+        let v = Vec2::new(-u.y, u.x).normalized();
+        OBB::from_projections(u, v, batch.minmax_projection(u), batch.minmax_projection(v))
+    }
+
+    /// (private) Monta a OBB a partir dos eixos e dos intervalos de projeção dos pontos
+    fn from_projections(u: Vec2<T>, v: Vec2<T>, u_range: (T, T), v_range: (T, T)) -> OBB<T> {
+        let ((min_u, max_u), (min_v, max_v)) = (u_range, v_range);
+        let extents = Vec2::new(max_u - min_u, max_v - min_v) / T::from_f64(2.0);
 
         // Calcula o centro da OBB
-        let center = ((min_u + max_u) * u + (min_v + max_v) * v) / 2.0;
+        let center = (u * (min_u + max_u) + v * (min_v + max_v)) / T::from_f64(2.0);
         OBB::new(center, extents, u, v)
     }
 
     /// Retorna as 4 pontas da OBB
-    fn corners(&self) -> (Vec2, Vec2, Vec2, Vec2) {
+    fn corners(&self) -> (Vec2<T>, Vec2<T>, Vec2<T>, Vec2<T>) {
         (
             self.center - (self.u * self.extents.x) - (self.v * self.extents.y),
             self.center + (self.u * self.extents.x) - (self.v * self.extents.y),
@@ -84,7 +99,7 @@ impl OBB {
     }
 
     /// Checa se a OBB contém um ponto
-    pub fn contains_point(&self, point: Vec2) -> bool {
+    pub fn contains_point(&self, point: Vec2<T>) -> bool {
         // Projeta o ponto pro espaço local da OBB
         let p_translated = point - self.center;
         let u_proj = p_translated.dot(self.u);
@@ -99,76 +114,101 @@ impl OBB {
         let (v1, v2, v3, v4) = self.corners();
 
         draw_line(
-            v1.x as f32,
-            v1.y as f32,
-            v2.x as f32,
-            v2.y as f32,
+            v1.x.to_f32(),
+            v1.y.to_f32(),
+            v2.x.to_f32(),
+            v2.y.to_f32(),
             thickness,
             color,
         );
         draw_line(
-            v2.x as f32,
-            v2.y as f32,
-            v3.x as f32,
-            v3.y as f32,
+            v2.x.to_f32(),
+            v2.y.to_f32(),
+            v3.x.to_f32(),
+            v3.y.to_f32(),
             thickness,
             color,
         );
         draw_line(
-            v3.x as f32,
-            v3.y as f32,
-            v4.x as f32,
-            v4.y as f32,
+            v3.x.to_f32(),
+            v3.y.to_f32(),
+            v4.x.to_f32(),
+            v4.y.to_f32(),
             thickness,
             color,
         );
         draw_line(
-            v4.x as f32,
-            v4.y as f32,
-            v1.x as f32,
-            v1.y as f32,
+            v4.x.to_f32(),
+            v4.y.to_f32(),
+            v1.x.to_f32(),
+            v1.y.to_f32(),
             thickness,
             color,
         );
     }
 }
 
-/// Função privada para pegar os valores mínimo e máximo das projeções
-/// de um vetor de pontos em um eixo.
-fn minmax_projection(points: &Vec<Vec2>, axis: Vec2) -> (f64, f64) {
-    let (mut min, mut max) = (INFINITY, -INFINITY);
-    for p in points {
-        let proj = p.dot(axis);
-        min = min.min(proj);
-        max = max.max(proj);
+// Métodos que dependem das transformações (só em f64)
+impl OBB {
+    #[inline]
+    #[must_use]
+    /// Cria uma OBB a partir da sua transformação (centro e rotação) e dos extents
+    pub fn from_isometry(isometry: &Isometry2, extents: Vec2) -> OBB {
+        let rotation = isometry.rotation;
+        OBB::new(
+            isometry.translation,
+            extents,
+            rotation.x_axis(),
+            rotation.y_axis(),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a rotação da OBB (a que leva o eixo X para o eixo U)
+    pub fn rotation(&self) -> Rot2 {
+        Rot2::from_vector(self.u)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a transformação do espaço local da OBB (centrada na origem) para o mundo
+    pub fn isometry(&self) -> Isometry2 {
+        Isometry2::from_parts(self.center, self.rotation())
     }
-    (min, max)
 }
 
-impl Collider for OBB {
-    fn grow(&mut self, width: f64, height: f64) {
-        self.extents.x = (self.extents.x + width).max(0.0);
-        self.extents.y = (self.extents.y + height).max(0.0);
+impl<T: Scalar> Collider<T> for OBB<T> {
+    fn grow(&mut self, width: T, height: T) {
+        self.extents.x = (self.extents.x + width).max(T::ZERO);
+        self.extents.y = (self.extents.y + height).max(T::ZERO);
     }
 
-    fn rotate(&mut self, theta: f64) {
+    fn rotate(&mut self, theta: T) {
+        // Gira só o eixo U e refaz o V perpendicular a ele (mantendo o lado),
+        // assim os eixos não perdem a ortogonalidade depois de muitas rotações
+        let handedness = if self.u.cross(self.v) < T::ZERO {
+            -T::ONE
+        } else {
+            T::ONE
+        };
         self.u = self.u.rotated(theta).normalized();
-        self.v = self.v.rotated(theta).normalized();
+        self.v = Vec2::new(-self.u.y, self.u.x) * handedness;
     }
 
-    fn set_center(&mut self, pos: Vec2) {
+    fn set_center(&mut self, pos: Vec2<T>) {
         self.center = pos;
     }
 
-    fn size(&self) -> Vec2 {
-        self.extents * 2.0
+    fn size(&self) -> Vec2<T> {
+        self.extents * T::from_f64(2.0)
     }
 
-    fn center(&self) -> Vec2 {
+    fn center(&self) -> Vec2<T> {
         self.center
     }
 
-    fn edges(&self) -> Vec<Line> {
+    fn edges(&self) -> Vec<Line<T>> {
         let (e1, e2, e3, e4) = self.corners();
         vec![
             Line::new(e1, e2),
@@ -182,7 +222,15 @@ impl Collider for OBB {
         self.draw(thickness, color);
     }
 
-    fn project(&self, axis: Vec2) -> (f64, f64) {
+    fn contains_point(&self, point: Vec2<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    fn closest_point(&self, point: Vec2<T>) -> Vec2<T> {
+        closest_point_on_edges(self.edges(), point)
+    }
+
+    fn project(&self, axis: Vec2<T>) -> (T, T) {
         // Projeção do centro da caixa sobre o eixo
         let center_p = self.center().dot(axis);
         // Projeção da metade da caixa sobre o eixo
@@ -192,7 +240,7 @@ impl Collider for OBB {
         (center_p - extents_p, center_p + extents_p)
     }
 
-    fn sat_axes(&self, _other: &dyn Collider) -> Vec<Vec2> {
+    fn sat_axes(&self, _other: &dyn Collider<T>) -> Vec<Vec2<T>> {
         vec![self.u, self.v]
     }
 }
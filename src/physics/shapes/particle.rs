@@ -1,78 +1,234 @@
-use crate::{algebra::Vec2, physics::shapes::Line};
+use crate::{
+    algebra::{Scalar, Vec2},
+    physics::{
+        Intersection,
+        shapes::{HitResponse, Line, Material, Wall},
+    },
+};
+use rayon::prelude::*;
 use macroquad::{
     color::Color,
     shapes::{draw_circle, draw_line},
 };
 
+/// Número máximo de colisões resolvidas em um único passo de uma partícula
+pub const MAX_BOUNCES: usize = 8;
+
+/// Distância que a partícula é empurrada para fora da parede depois de uma colisão
+/// (evita que o próximo teste de interseção encontre a mesma parede em t = 0)
+pub const SURFACE_EPS: f64 = 1e-6;
+
+/// Tolerância no parâmetro `u` da parede: um movimento que passa exatamente pela ponta
+/// compartilhada de duas paredes não pode "errar" as duas por erro de arredondamento
+const ENDPOINT_EPS: f64 = 1e-9;
+
 #[derive(Clone, Copy, PartialEq)]
-pub struct Particle {
-    pub position: Vec2,
-    pub speed: Vec2,
+pub struct Particle<T = f64> {
+    pub position: Vec2<T>,
+    pub speed: Vec2<T>,
+    /// Massa da partícula (usada nas interações entre partículas, como a gravidade)
+    pub mass: T,
+    /// Carga elétrica da partícula (usada pelos campos elétricos e magnéticos)
+    pub charge: T,
 }
 
-impl Particle {
+impl<T: Scalar> Particle<T> {
     #[inline]
     #[must_use]
-    /// Construtor da partícula
-    pub fn new(position: Vec2, speed: Vec2) -> Particle {
-        Particle { position, speed }
+    /// Construtor da partícula (com massa 1)
+    pub fn new(position: Vec2<T>, speed: Vec2<T>) -> Particle<T> {
+        Particle::with_mass(position, speed, T::ONE)
     }
 
-    /// Atualiza a posição da partícula conforme variação de tempo e paredes do mundo
-    pub fn update(&mut self, delta: f64, walls: &Vec<Line>) {
-        let next_pos = self.position + self.speed * delta;
-        let movement_line = Line::new(self.position, next_pos);
-
-        // Obtém a interseção mais próxima da linha do movimento da partícula
-        let closest_intersection = walls
-            .iter()
-            // Calcula todas as interseções e filtra aquelas que são válidas (!= None) com 0.0 <= t <= 1.0
-            .filter_map(|wall| {
-                movement_line.intersection(*wall).filter(|intersection| {
-                    0.0 <= intersection.t && intersection.t <= 1.0
-                    && 0.0 <= intersection.u && intersection.u <= 1.0
-                })
-            })
-            .min_by(|a, b| {
-                // Pega a interseção mais próxima da posição atual da partícula
-                a.p.distance_to_squared(self.position)
-                    .total_cmp(&b.p.distance_to_squared(self.position))
-            });
+    #[inline]
+    #[must_use]
+    /// Construtor da partícula com massa definida (sem carga)
+    pub fn with_mass(position: Vec2<T>, speed: Vec2<T>, mass: T) -> Particle<T> {
+        Particle::with_charge(position, speed, mass, T::ZERO)
+    }
 
-        // Se houve interseção, "quica" a partícula na parede
-        if let Some(intersection) = closest_intersection {
-            // Movimento total a ser feito
-            let total_movement = (self.speed * delta).length();
-            // Movimento feito até a colisão com a parede
-            let partial_movement = self.position.distance_to(intersection.p);
-            // Movimento restante a ser feito
-            let remaining_movement = total_movement - partial_movement;
+    #[inline]
+    #[must_use]
+    /// Construtor da partícula com massa e carga definidas
+    pub fn with_charge(position: Vec2<T>, speed: Vec2<T>, mass: T, charge: T) -> Particle<T> {
+        Particle {
+            position,
+            speed,
+            mass,
+            charge,
+        }
+    }
+
+    /// Atualiza a posição da partícula conforme variação de tempo e paredes do mundo.
+    /// Resolve várias colisões no mesmo passo (até `MAX_BOUNCES`): depois de cada quique,
+    /// o movimento restante é testado de novo contra as paredes, evitando que a partícula
+    /// escape por cantos e frestas. Retorna quantos quiques aconteceram.
+    pub fn update(&mut self, delta: T, walls: &[Line<T>]) -> usize {
+        // Tempo do passo que ainda falta ser percorrido
+        let mut remaining = delta;
+        let mut bounces = 0;
+
+        while bounces < MAX_BOUNCES {
+            let next_pos = self.position + self.speed * remaining;
+            let movement_line = Line::new(self.position, next_pos);
+
+            // Obtém a interseção mais próxima da linha do movimento da partícula
+            let Some((_, intersection)) = closest_hit(movement_line, walls.iter().copied()) else {
+                self.position = next_pos;
+                return bounces;
+            };
 
-            // Reflete a velocidade na normal da parede.
+            // Se houve interseção, "quica" a partícula na parede
+            let away = away_from_surface(self.speed, intersection.normal);
             self.speed = self.speed.bounce(intersection.normal);
-            self.position = intersection.p + self.speed.normalized() * remaining_movement;
-        } else {
-            self.position = next_pos
+            self.position = intersection.p + away * T::from_f64(SURFACE_EPS);
+            remaining *= T::ONE - intersection.t;
+            bounces += 1;
         }
+
+        // Limite de quiques atingido: a partícula fica parada no último ponto de contato
+        bounces
+    }
+
+    /// Atualiza um lote de partículas em paralelo (usando Rayon) contra as mesmas paredes.
+    /// Cada partícula só lê as paredes, então o resultado é idêntico ao de chamar `update`
+    /// em cada partícula, independente do número de threads.
+    pub fn par_update(particles: &mut [Particle<T>], delta: T, walls: &[Line<T>]) {
+        particles
+            .par_iter_mut()
+            .for_each(|particle| {
+                particle.update(delta, walls);
+            });
     }
 
     #[inline]
     /// Desenha a partícula
     pub fn draw(&self, color: Color) {
-        draw_circle(self.position.x as f32, self.position.y as f32, 3.0, color);
+        draw_circle(
+            self.position.x.to_f32(),
+            self.position.y.to_f32(),
+            3.0,
+            color,
+        );
     }
 
     #[inline]
     /// Desenha a linha do próximo movimento da partícula
-    pub fn draw_movement_line(&self, delta: f64, thickness: f32, color: Color) {
+    pub fn draw_movement_line(&self, delta: T, thickness: f32, color: Color) {
         let next_pos = self.position + self.speed * delta;
         draw_line(
-            self.position.x as f32,
-            self.position.y as f32,
-            next_pos.x as f32,
-            next_pos.y as f32,
+            self.position.x.to_f32(),
+            self.position.y.to_f32(),
+            next_pos.x.to_f32(),
+            next_pos.y.to_f32(),
             thickness,
             color,
         );
     }
 }
+
+// Paredes com material (só em f64)
+impl Particle {
+    /// Atualiza a posição da partícula conforme variação de tempo e paredes com material.
+    /// Assim como `update`, resolve várias colisões no mesmo passo.
+    /// Retorna `false` se a partícula morreu ao atingir uma parede.
+    pub fn update_with_walls(&mut self, delta: f64, walls: &[Wall]) -> bool {
+        let mut remaining = delta;
+
+        for _ in 0..MAX_BOUNCES {
+            let next_pos = self.position + self.speed * remaining;
+            let movement_line = Line::new(self.position, next_pos);
+
+            // Paredes de mão única só contam se o movimento vem do lado da normal
+            let Some((index, intersection)) = walls
+                .iter()
+                .enumerate()
+                .filter(|(_, wall)| wall.blocks(self.speed))
+                .filter_map(|(i, wall)| {
+                    closest_hit(movement_line, std::iter::once(wall.line))
+                        .map(|(_, intersection)| (i, intersection))
+                })
+                .min_by(|(_, a), (_, b)| a.t.total_cmp(&b.t))
+            else {
+                self.position = next_pos;
+                return true;
+            };
+
+            let wall = &walls[index];
+            let away = away_from_surface(self.speed, intersection.normal);
+
+            match (wall.on_hit)(self, &intersection) {
+                HitResponse::Bounce => {
+                    self.speed = wall.material.bounce(self.speed, intersection.normal);
+                }
+                HitResponse::Absorb => {
+                    let material = Material::new(0.0, wall.material.friction);
+                    self.speed = material.bounce(self.speed, intersection.normal);
+                }
+                HitResponse::Stick => {
+                    self.speed = Vec2::NULL;
+                    self.position = intersection.p + away * SURFACE_EPS;
+                    return true;
+                }
+                HitResponse::Kill => {
+                    self.position = intersection.p;
+                    return false;
+                }
+            }
+
+            self.position = intersection.p + away * SURFACE_EPS;
+            remaining *= 1.0 - intersection.t;
+        }
+
+        true
+    }
+}
+
+/// Retorna o índice e a interseção da parede mais próxima do início de uma linha de movimento
+/// (apenas interseções válidas, com 0.0 <= t <= 1.0 e 0.0 <= u <= 1.0, a menos de `ENDPOINT_EPS`)
+pub(crate) fn closest_hit<T: Scalar>(
+    movement_line: Line<T>,
+    walls: impl Iterator<Item = Line<T>>,
+) -> Option<(usize, Intersection<T>)> {
+    let (zero, one, eps) = (T::ZERO, T::ONE, T::from_f64(ENDPOINT_EPS));
+    walls
+        .enumerate()
+        // Calcula todas as interseções e filtra aquelas que são válidas (!= None) com 0.0 <= t <= 1.0
+        .filter_map(|(i, wall)| {
+            movement_line
+                .intersection(wall)
+                .filter(|intersection| {
+                    zero <= intersection.t && intersection.t <= one
+                    && -eps <= intersection.u && intersection.u <= one + eps
+                })
+                .map(|intersection| (i, intersection))
+        })
+        // Pega a interseção mais próxima da posição atual da partícula
+        .min_by(|(_, a), (_, b)| a.t.total_cmp(&b.t))
+}
+
+/// (private) Retorna a normal da superfície orientada para o lado de onde
+/// veio uma partícula com velocidade `speed` (o lado oposto ao movimento)
+fn away_from_surface<T: Scalar>(speed: Vec2<T>, normal: Vec2<T>) -> Vec2<T> {
+    if speed.dot(normal) > T::ZERO { -normal } else { normal }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_through_shared_corner_hits_a_wall() {
+        // O movimento passa exatamente pela ponta comum das duas paredes, mas o
+        // arredondamento deixa o `u` das duas interseções um pouco fora de [0, 1]
+        let corner = Vec2::new(887.413, 280.533);
+        let walls = [
+            Line::new(Vec2::new(164.388, 314.152), corner),
+            Line::new(corner, Vec2::new(901.616, 197.682)),
+        ];
+        let start = Vec2::new(858.219, 16.214);
+        let mut particle = Particle::new(start, (corner - start) * 2.0);
+
+        assert_eq!(particle.update(1.0, &walls), 1);
+    }
+}
@@ -0,0 +1,238 @@
+use macroquad::color::Color;
+
+use crate::{
+    algebra::{Scalar, Vec2},
+    physics::shapes::{AABB, Collider, Line, collider::closest_point_on_edges},
+};
+
+/// Polígono convexo (os vértices podem estar em qualquer sentido de giro)
+#[derive(Clone, PartialEq, Debug)]
+pub struct Polygon<T = f64> {
+    pub vertices: Vec<Vec2<T>>,
+}
+
+impl<T: Scalar> Polygon<T> {
+    #[inline]
+    #[must_use]
+    /// Construtor do polígono. Os vértices devem formar um polígono convexo.
+    pub fn new(vertices: Vec<Vec2<T>>) -> Polygon<T> {
+        Polygon { vertices }
+    }
+
+    #[must_use]
+    /// Cria o polígono equivalente a uma AABB
+    pub fn from_aabb(aabb: &AABB<T>) -> Polygon<T> {
+        Polygon::new(aabb.edges().into_iter().map(|edge| edge.p1).collect())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a área com sinal do polígono (positiva se os vértices giram de X para Y)
+    pub fn signed_area(&self) -> T {
+        signed_area(&self.vertices)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a área do polígono
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o centroide (centro de área) do polígono
+    pub fn centroid(&self) -> Vec2<T> {
+        centroid(&self.vertices)
+    }
+
+    #[must_use]
+    /// Recorta um polígono qualquer (`subject`) contra este polígono convexo
+    /// (algoritmo de Sutherland-Hodgman). Retorna os vértices da interseção
+    /// (vazio se este polígono tiver área 0, já que ele não tem lado de dentro).
+    pub fn clip(&self, subject: &[Vec2<T>]) -> Vec<Vec2<T>> {
+        let orientation = self.signed_area().signum();
+        if orientation == T::ZERO {
+            return Vec::new();
+        }
+        let mut output = subject.to_vec();
+
+        for edge in self.edges() {
+            if output.is_empty() {
+                break;
+            }
+            let input = std::mem::take(&mut output);
+            // Ponto do lado de dentro da aresta
+            let inside =
+                |p: Vec2<T>| (edge.p2 - edge.p1).cross(p - edge.p1) * orientation >= T::ZERO;
+
+            for i in 0..input.len() {
+                let current = input[i];
+                let previous = input[(i + input.len() - 1) % input.len()];
+                match (inside(previous), inside(current)) {
+                    (true, true) => output.push(current),
+                    (true, false) => output.push(edge_crossing(edge, previous, current)),
+                    (false, true) => {
+                        output.push(edge_crossing(edge, previous, current));
+                        output.push(current);
+                    }
+                    (false, false) => {}
+                }
+            }
+        }
+        output
+    }
+
+    /// Desenha o contorno do polígono
+    pub fn draw(&self, thickness: f32, color: Color) {
+        for edge in self.edges() {
+            edge.draw(thickness, color);
+        }
+    }
+}
+
+/// (private) Ponto em que o segmento a-b cruza a reta de uma aresta
+fn edge_crossing<T: Scalar>(edge: Line<T>, a: Vec2<T>, b: Vec2<T>) -> Vec2<T> {
+    let direction = edge.p2 - edge.p1;
+    let da = direction.cross(a - edge.p1);
+    let db = direction.cross(b - edge.p1);
+    a + (b - a) * (da / (da - db))
+}
+
+/// Área com sinal de uma lista de vértices (fórmula do laço / shoelace)
+pub(crate) fn signed_area<T: Scalar>(vertices: &[Vec2<T>]) -> T {
+    let n = vertices.len();
+    (0..n)
+        .map(|i| vertices[i].cross(vertices[(i + 1) % n]))
+        .sum::<T>()
+        / T::from_f64(2.0)
+}
+
+/// Centroide de uma lista de vértices (média dos vértices se a área for nula)
+pub(crate) fn centroid<T: Scalar>(vertices: &[Vec2<T>]) -> Vec2<T> {
+    let n = vertices.len();
+    let area = signed_area(vertices);
+    if area.abs() <= T::EPSILON {
+        return vertices.iter().copied().sum::<Vec2<T>>() / T::from_f64(n.max(1) as f64);
+    }
+    let moment: Vec2<T> = (0..n)
+        .map(|i| {
+            let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+            (a + b) * a.cross(b)
+        })
+        .sum();
+    moment / (T::from_f64(6.0) * area)
+}
+
+impl<T: Scalar> Collider<T> for Polygon<T> {
+    fn grow(&mut self, width: T, height: T) {
+        let size = self.size();
+        let center = self.centroid();
+        let scale_x = if size.x > T::ZERO {
+            (size.x + width) / size.x
+        } else {
+            T::ONE
+        };
+        let scale_y = if size.y > T::ZERO {
+            (size.y + height) / size.y
+        } else {
+            T::ONE
+        };
+        if scale_x >= T::ZERO && scale_y >= T::ZERO {
+            for v in &mut self.vertices {
+                let offset = *v - center;
+                *v = center + Vec2::new(offset.x * scale_x, offset.y * scale_y);
+            }
+        }
+    }
+
+    fn rotate(&mut self, theta: T) {
+        let center = self.centroid();
+        for v in &mut self.vertices {
+            *v = center + (*v - center).rotated(theta);
+        }
+    }
+
+    fn set_center(&mut self, pos: Vec2<T>) {
+        let offset = pos - self.centroid();
+        for v in &mut self.vertices {
+            *v += offset;
+        }
+    }
+
+    fn size(&self) -> Vec2<T> {
+        AABB::enclosing(&self.vertices).size()
+    }
+
+    fn center(&self) -> Vec2<T> {
+        self.centroid()
+    }
+
+    fn edges(&self) -> Vec<Line<T>> {
+        let n = self.vertices.len();
+        (0..n)
+            .map(|i| Line::new(self.vertices[i], self.vertices[(i + 1) % n]))
+            .collect()
+    }
+
+    fn draw(&self, thickness: f32, color: Color) {
+        self.draw(thickness, color);
+    }
+
+    fn contains_point(&self, point: Vec2<T>) -> bool {
+        let orientation = self.signed_area().signum();
+        self.edges()
+            .iter()
+            .all(|edge| (edge.p2 - edge.p1).cross(point - edge.p1) * orientation > T::ZERO)
+    }
+
+    fn closest_point(&self, point: Vec2<T>) -> Vec2<T> {
+        closest_point_on_edges(self.edges(), point)
+    }
+
+    fn project(&self, axis: Vec2<T>) -> (T, T) {
+        self.vertices
+            .iter()
+            .map(|v| v.dot(axis))
+            .fold((T::INFINITY, -T::INFINITY), |(min, max), p| {
+                (min.min(p), max.max(p))
+            })
+    }
+
+    fn sat_axes(&self, _other: &dyn Collider<T>) -> Vec<Vec2<T>> {
+        self.edges()
+            .iter()
+            .filter(|edge| !edge.is_degenerate())
+            .map(|edge| edge.normal())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::Fixed;
+
+    /// Polígono de área 0 (vértices colineares) e um quadrado para recortar
+    fn degenerate_case<T: Scalar>() -> (Polygon<T>, Vec<Vec2<T>>) {
+        let v = |x: f64, y: f64| Vec2::new(T::from_f64(x), T::from_f64(y));
+        let line = Polygon::new(vec![v(0.0, 0.0), v(1.0, 1.0), v(2.0, 2.0)]);
+        let square = vec![v(0.0, 0.0), v(2.0, 0.0), v(2.0, 2.0), v(0.0, 2.0)];
+        (line, square)
+    }
+
+    #[test]
+    fn degenerate_polygon_is_the_same_in_every_backend() {
+        let (line, square) = degenerate_case::<f64>();
+        assert!(line.clip(&square).is_empty());
+        assert!(!line.contains_point(Vec2::new(1.0, 1.0)));
+
+        let (line, square) = degenerate_case::<Fixed>();
+        assert!(line.clip(&square).is_empty());
+        assert!(!line.contains_point(Vec2::new(Fixed::ONE, Fixed::ONE)));
+
+        assert_eq!(Scalar::signum(0.0f64), 0.0);
+        assert_eq!(Scalar::signum(0.0f32), 0.0);
+        assert_eq!(Scalar::signum(Fixed::ZERO), Fixed::ZERO);
+    }
+}
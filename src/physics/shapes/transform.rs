@@ -0,0 +1,282 @@
+use macroquad::color::Color;
+
+use crate::{
+    algebra::{Isometry2, Mat3, Vec2},
+    physics::shapes::{AABB, Circle, Collider, Line, OBB, Polygon},
+};
+
+/// Número de lados do polígono que aproxima um círculo deformado (elipse)
+const ELLIPSE_SEGMENTS: usize = 32;
+
+/// Formas que podem ser levadas para outro lugar por uma transformação rígida
+pub trait RigidTransform {
+    /// Tipo da forma transformada (uma AABB girada vira uma OBB, por exemplo)
+    type Output;
+
+    /// Retorna a forma transformada
+    fn transformed(&self, isometry: &Isometry2) -> Self::Output;
+}
+
+impl RigidTransform for Line {
+    type Output = Line;
+    fn transformed(&self, isometry: &Isometry2) -> Line {
+        Line::new(
+            isometry.transform_point(self.p1),
+            isometry.transform_point(self.p2),
+        )
+    }
+}
+
+impl RigidTransform for Circle {
+    type Output = Circle;
+    fn transformed(&self, isometry: &Isometry2) -> Circle {
+        Circle::new(isometry.transform_point(self.center), self.radius)
+    }
+}
+
+impl RigidTransform for AABB {
+    type Output = OBB;
+    fn transformed(&self, isometry: &Isometry2) -> OBB {
+        let local = Isometry2::from_translation(self.center());
+        OBB::from_isometry(&(*isometry * local), self.size() / 2.0)
+    }
+}
+
+impl RigidTransform for OBB {
+    type Output = OBB;
+    fn transformed(&self, isometry: &Isometry2) -> OBB {
+        OBB::new(
+            isometry.transform_point(self.center),
+            self.extents,
+            isometry.transform_vector(self.u),
+            isometry.transform_vector(self.v),
+        )
+    }
+}
+
+impl RigidTransform for Polygon {
+    type Output = Polygon;
+    fn transformed(&self, isometry: &Isometry2) -> Polygon {
+        Polygon::new(
+            self.vertices
+                .iter()
+                .map(|v| isometry.transform_point(*v))
+                .collect(),
+        )
+    }
+}
+
+/// Forma descrita no seu próprio espaço local mais a transformação para o mundo.
+/// Mover ou girar o objeto só muda `transform`; a forma local nunca acumula erro.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Local<S> {
+    pub shape: S,
+    pub transform: Isometry2,
+}
+
+impl<S> Local<S> {
+    #[inline]
+    #[must_use]
+    /// Construtor da forma local
+    pub fn new(shape: S, transform: Isometry2) -> Local<S> {
+        Local { shape, transform }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Leva um ponto do mundo para o espaço local da forma
+    pub fn to_local(&self, point: Vec2) -> Vec2 {
+        self.transform.inverse_transform_point(point)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Leva um ponto do espaço local da forma para o mundo
+    pub fn to_world(&self, point: Vec2) -> Vec2 {
+        self.transform.transform_point(point)
+    }
+}
+
+impl<S: RigidTransform> Local<S> {
+    #[inline]
+    #[must_use]
+    /// Retorna a forma no mundo
+    pub fn world(&self) -> S::Output {
+        self.shape.transformed(&self.transform)
+    }
+}
+
+impl<S: Collider> Local<S> {
+    #[inline]
+    #[must_use]
+    /// Checa se um ponto do mundo está dentro da forma (testado no espaço local)
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.shape.contains_point(self.to_local(point))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o ponto da borda da forma mais próximo de um ponto do mundo
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.to_world(self.shape.closest_point(self.to_local(point)))
+    }
+}
+
+/// Formas que podem passar por uma transformação afim (escala, cisalhamento...)
+pub trait AffineTransform {
+    /// Tipo da forma transformada
+    type Output;
+
+    /// Retorna a forma transformada
+    fn affine(&self, matrix: &Mat3) -> Self::Output;
+}
+
+/// Resultado de uma transformação afim em uma forma que pode mudar de tipo.
+/// Caixas continuam caixas se a transformação mantiver os ângulos retos e viram
+/// paralelogramos (polígonos) caso contrário; círculos só continuam círculos
+/// com escala uniforme.
+#[derive(Clone, PartialEq)]
+pub enum TransformedShape {
+    Circle(Circle),
+    OBB(OBB),
+    Polygon(Polygon),
+}
+
+impl AffineTransform for Vec<Vec2> {
+    type Output = Vec<Vec2>;
+    fn affine(&self, matrix: &Mat3) -> Vec<Vec2> {
+        matrix.transform_points(self)
+    }
+}
+
+impl AffineTransform for Line {
+    type Output = Line;
+    fn affine(&self, matrix: &Mat3) -> Line {
+        Line::new(
+            matrix.transform_point(self.p1),
+            matrix.transform_point(self.p2),
+        )
+    }
+}
+
+impl AffineTransform for Polygon {
+    type Output = Polygon;
+    fn affine(&self, matrix: &Mat3) -> Polygon {
+        Polygon::new(matrix.transform_points(&self.vertices))
+    }
+}
+
+impl AffineTransform for OBB {
+    type Output = TransformedShape;
+    fn affine(&self, matrix: &Mat3) -> TransformedShape {
+        let center = matrix.transform_point(self.center);
+        let u = matrix.transform_vector(self.u * self.extents.x);
+        let v = matrix.transform_vector(self.v * self.extents.y);
+
+        // Os eixos continuam perpendiculares: ainda é uma caixa
+        let scale = u.length_squared().max(v.length_squared());
+        if u.dot(v).abs() <= 1e-9 * scale && !u.is_same(Vec2::NULL) && !v.is_same(Vec2::NULL) {
+            let extents = Vec2::new(u.length(), v.length());
+            return TransformedShape::OBB(OBB::new(center, extents, u, v));
+        }
+        TransformedShape::Polygon(Polygon::new(vec![
+            center - u - v,
+            center + u - v,
+            center + u + v,
+            center - u + v,
+        ]))
+    }
+}
+
+impl AffineTransform for AABB {
+    type Output = TransformedShape;
+    fn affine(&self, matrix: &Mat3) -> TransformedShape {
+        let extents = self.size() / 2.0;
+        OBB::new(self.center(), extents, Vec2::X, Vec2::Y).affine(matrix)
+    }
+}
+
+impl AffineTransform for Circle {
+    type Output = TransformedShape;
+    fn affine(&self, matrix: &Mat3) -> TransformedShape {
+        let center = matrix.transform_point(self.center);
+        if matrix.is_similarity() {
+            let radius = matrix.transform_vector(Vec2::X).length() * self.radius;
+            return TransformedShape::Circle(Circle::new(center, radius));
+        }
+        // Elipse: aproximada por um polígono
+        let vertices = (0..ELLIPSE_SEGMENTS)
+            .map(|i| {
+                let theta = std::f64::consts::TAU * i as f64 / ELLIPSE_SEGMENTS as f64;
+                center + matrix.transform_vector(Vec2::from_angle(theta) * self.radius)
+            })
+            .collect();
+        TransformedShape::Polygon(Polygon::new(vertices))
+    }
+}
+
+impl TransformedShape {
+    /// (private) A forma como collider
+    fn collider(&self) -> &dyn Collider {
+        match self {
+            TransformedShape::Circle(circle) => circle,
+            TransformedShape::OBB(obb) => obb,
+            TransformedShape::Polygon(polygon) => polygon,
+        }
+    }
+
+    /// (private) A forma como collider mutável
+    fn collider_mut(&mut self) -> &mut dyn Collider {
+        match self {
+            TransformedShape::Circle(circle) => circle,
+            TransformedShape::OBB(obb) => obb,
+            TransformedShape::Polygon(polygon) => polygon,
+        }
+    }
+}
+
+impl Collider for TransformedShape {
+    fn set_center(&mut self, pos: Vec2) {
+        self.collider_mut().set_center(pos);
+    }
+
+    fn rotate(&mut self, theta: f64) {
+        self.collider_mut().rotate(theta);
+    }
+
+    fn grow(&mut self, width: f64, height: f64) {
+        self.collider_mut().grow(width, height);
+    }
+
+    fn size(&self) -> Vec2 {
+        self.collider().size()
+    }
+
+    fn draw(&self, thickness: f32, color: Color) {
+        self.collider().draw(thickness, color);
+    }
+
+    fn center(&self) -> Vec2 {
+        self.collider().center()
+    }
+
+    fn contains_point(&self, point: Vec2) -> bool {
+        self.collider().contains_point(point)
+    }
+
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.collider().closest_point(point)
+    }
+
+    fn project(&self, axis: Vec2) -> (f64, f64) {
+        self.collider().project(axis)
+    }
+
+    fn edges(&self) -> Vec<Line> {
+        self.collider().edges()
+    }
+
+    fn sat_axes(&self, other: &dyn Collider) -> Vec<Vec2> {
+        self.collider().sat_axes(other)
+    }
+}
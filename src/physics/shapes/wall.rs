@@ -0,0 +1,179 @@
+use macroquad::color::Color;
+
+use crate::{
+    algebra::Vec2,
+    physics::{
+        Intersection,
+        shapes::{Line, Particle},
+    },
+};
+
+/// Material de uma parede. Define como a velocidade de uma partícula muda no impacto.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Material {
+    /// Coeficiente de restituição aplicado à componente normal da velocidade
+    /// (0 = absorve todo o impacto, 1 = quique perfeitamente elástico)
+    pub restitution: f64,
+    /// Coeficiente de atrito (Coulomb) aplicado à componente tangencial da velocidade
+    /// (0 = sem atrito)
+    pub friction: f64,
+}
+
+impl Material {
+    /// Material perfeitamente elástico e sem atrito (equivalente a `Vec2::bounce`)
+    pub const ELASTIC: Material = Material {
+        restitution: 1.0,
+        friction: 0.0,
+    };
+
+    #[inline]
+    #[must_use]
+    /// Construtor do material
+    pub fn new(restitution: f64, friction: f64) -> Material {
+        Material {
+            restitution,
+            friction,
+        }
+    }
+
+    #[must_use]
+    /// Retorna a velocidade após o impacto com uma superfície de normal `normal`.
+    /// A componente normal é refletida e escalada pela restituição, e a componente
+    /// tangencial perde `friction * |Δv normal|` (nunca invertendo o seu sentido).
+    pub fn bounce(&self, speed: Vec2, normal: Vec2) -> Vec2 {
+        let normal_speed = normal * speed.dot(normal);
+        let tangent_speed = speed - normal_speed;
+
+        // Impulso normal do impacto -> limita o atrito (modelo de Coulomb)
+        let normal_impulse = normal_speed.length() * (1.0 + self.restitution);
+        let tangent_length = tangent_speed.length();
+        let tangent_scale = if tangent_length > 0.0 {
+            (1.0 - self.friction * normal_impulse / tangent_length).max(0.0)
+        } else {
+            0.0
+        };
+
+        tangent_speed * tangent_scale - normal_speed * self.restitution
+    }
+}
+
+impl Default for Material {
+    fn default() -> Material {
+        Material::ELASTIC
+    }
+}
+
+/// O que acontece com uma partícula quando ela atinge uma parede
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HitResponse {
+    /// Quica de acordo com o material da parede
+    Bounce,
+    /// Perde toda a velocidade normal e continua deslizando pela parede (com atrito)
+    Absorb,
+    /// Gruda na parede no ponto de impacto
+    Stick,
+    /// A partícula morre
+    Kill,
+}
+
+/// Callback chamado quando uma partícula atinge uma parede
+pub type HitCallback = fn(&Particle, &Intersection) -> HitResponse;
+
+/// Uma linha com material e comportamento de impacto
+#[derive(Clone, Copy)]
+pub struct Wall {
+    pub line: Line,
+    pub material: Material,
+    pub on_hit: HitCallback,
+    /// Se a parede só bloqueia partículas vindas do lado da normal (`line.normal()`).
+    /// Partículas vindas do outro lado a atravessam (plataformas de mão única).
+    pub one_way: bool,
+}
+
+impl Wall {
+    #[inline]
+    #[must_use]
+    /// Construtor de uma parede que quica as partículas de acordo com o material
+    pub fn new(line: Line, material: Material) -> Wall {
+        Wall::with_callback(line, material, bounce)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construtor de uma parede com um callback de impacto customizado
+    pub fn with_callback(line: Line, material: Material, on_hit: HitCallback) -> Wall {
+        Wall {
+            line,
+            material,
+            on_hit,
+            one_way: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Parede de mão única: só quica partículas vindas do lado da normal da linha
+    pub fn one_way(line: Line, material: Material) -> Wall {
+        Wall {
+            one_way: true,
+            ..Wall::new(line, material)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se a parede bloqueia um movimento nessa direção
+    pub fn blocks(&self, movement: Vec2) -> bool {
+        !self.one_way || movement.dot(self.line.normal()) < 0.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Parede que absorve a velocidade normal das partículas (elas deslizam por ela)
+    pub fn absorbing(line: Line, friction: f64) -> Wall {
+        Wall::with_callback(line, Material::new(0.0, friction), absorb)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Parede que gruda as partículas no ponto de impacto
+    pub fn sticky(line: Line) -> Wall {
+        Wall::with_callback(line, Material::new(0.0, 1.0), stick)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Parede que mata as partículas que a atingem
+    pub fn killing(line: Line) -> Wall {
+        Wall::with_callback(line, Material::ELASTIC, kill)
+    }
+
+    #[inline]
+    /// Desenha a parede na tela
+    pub fn draw(&self, thickness: f32, color: Color) {
+        self.line.draw(thickness, color);
+    }
+}
+
+impl From<Line> for Wall {
+    fn from(line: Line) -> Wall {
+        Wall::new(line, Material::ELASTIC)
+    }
+}
+
+/// Callbacks padrão das paredes
+fn bounce(_particle: &Particle, _intersection: &Intersection) -> HitResponse {
+    HitResponse::Bounce
+}
+
+fn absorb(_particle: &Particle, _intersection: &Intersection) -> HitResponse {
+    HitResponse::Absorb
+}
+
+fn stick(_particle: &Particle, _intersection: &Intersection) -> HitResponse {
+    HitResponse::Stick
+}
+
+fn kill(_particle: &Particle, _intersection: &Intersection) -> HitResponse {
+    HitResponse::Kill
+}
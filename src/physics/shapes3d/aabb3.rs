@@ -0,0 +1,145 @@
+use macroquad::{color::Color, models::draw_cube_wires};
+
+use crate::{
+    algebra::{Scalar, Vec3},
+    physics::shapes3d::{Collider3, collider3::closest_point_on_box},
+};
+
+/// Caixa 3D alinhada aos eixos
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AABB3<T = f64> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T: Scalar> AABB3<T> {
+    #[must_use]
+    /// Cria uma AABB3 com dois pontos como limites. Esta função independe da ordem
+    /// dos pontos e constrói uma AABB3 com min = (min_x,min_y,min_z) e max = (max_x,max_y,max_z)
+    pub fn new(p1: Vec3<T>, p2: Vec3<T>) -> AABB3<T> {
+        AABB3 {
+            min: p1.min(p2),
+            max: p1.max(p2),
+        }
+    }
+
+    #[must_use]
+    /// Retorna uma AABB3 que contém todos os pontos. Pânico se points.len() == 0
+    pub fn enclosing(points: &[Vec3<T>]) -> AABB3<T> {
+        assert!(!points.is_empty(), "Número de pontos deve ser maior que 0!");
+        let infinity = Vec3::new(T::INFINITY, T::INFINITY, T::INFINITY);
+        let (min, max) = points.iter().fold((infinity, -infinity), |(min, max), p| {
+            (min.min(*p), max.max(*p))
+        });
+        AABB3 { min, max }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a largura (X) da AABB3
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a altura (Y) da AABB3
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a profundidade (Z) da AABB3
+    pub fn depth(&self) -> T {
+        self.max.z - self.min.z
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o volume da AABB3
+    pub fn volume(&self) -> T {
+        self.width() * self.height() * self.depth()
+    }
+
+    #[inline]
+    /// Desenha o frame da AABB3 (precisa de uma câmera 3D da Macroquad ativa)
+    pub fn draw(&self, color: Color) {
+        draw_cube_wires(
+            ((self.min + self.max) / T::from_f64(2.0)).into(),
+            (self.max - self.min).into(),
+            color,
+        );
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se um ponto está dentro da bounding box
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        point.x > self.min.x
+            && point.x < self.max.x
+            && point.y > self.min.y
+            && point.y < self.max.y
+            && point.z > self.min.z
+            && point.z < self.max.z
+    }
+}
+
+impl<T: Scalar> Collider3<T> for AABB3<T> {
+    fn grow(&mut self, amount: Vec3<T>) {
+        let half = amount / T::from_f64(2.0);
+        let (new_min, new_max) = (self.min - half, self.max + half);
+
+        if new_min.x <= new_max.x && new_min.y <= new_max.y && new_min.z <= new_max.z {
+            self.min = new_min;
+            self.max = new_max;
+        }
+    }
+
+    fn rotate(&mut self, _axis: Vec3<T>, _theta: T) {}
+
+    fn set_center(&mut self, pos: Vec3<T>) {
+        let offset = pos - self.center();
+        self.max += offset;
+        self.min += offset;
+    }
+
+    fn size(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+
+    fn center(&self) -> Vec3<T> {
+        (self.min + self.max) / T::from_f64(2.0)
+    }
+
+    fn draw(&self, color: Color) {
+        self.draw(color);
+    }
+
+    fn contains_point(&self, point: Vec3<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let extents = self.size() / T::from_f64(2.0);
+        self.center() + closest_point_on_box(extents, point - self.center())
+    }
+
+    fn project(&self, axis: Vec3<T>) -> (T, T) {
+        let extents = self.size() / T::from_f64(2.0);
+        // Projeção do centro da caixa sobre o eixo
+        let center_p = self.center().dot(axis);
+        // Projeção da metade da caixa sobre o eixo
+        let extents_p = extents.dot(axis.abs());
+        // min, max
+        (center_p - extents_p, center_p + extents_p)
+    }
+
+    fn edge_directions(&self) -> Vec<Vec3<T>> {
+        vec![Vec3::X, Vec3::Y, Vec3::Z]
+    }
+
+    fn sat_axes(&self, _other: &dyn Collider3<T>) -> Vec<Vec3<T>> {
+        vec![Vec3::X, Vec3::Y, Vec3::Z]
+    }
+}
@@ -0,0 +1,104 @@
+use macroquad::color::Color;
+
+use crate::algebra::{Scalar, Vec3};
+
+/// Equivalente 3D do `Collider`: engloba objetos em geral e lida com suas colisões.
+/// Genérico no tipo dos números (f64 por padrão).
+pub trait Collider3<T: Scalar = f64> {
+    /// Move o centro do objeto para uma posição específica
+    fn set_center(&mut self, pos: Vec3<T>);
+
+    /// Rotaciona o objeto em theta radianos ao redor de um eixo unitário que passa
+    /// pelo centro (só funciona para OBB3 no código atual)
+    fn rotate(&mut self, axis: Vec3<T>, theta: T);
+
+    /// Aumenta/diminui o tamanho do objeto
+    fn grow(&mut self, amount: Vec3<T>);
+
+    /// Retorna a largura, altura e profundidade do objeto
+    fn size(&self) -> Vec3<T>;
+
+    /// Desenha o frame do objeto (precisa de uma câmera 3D da Macroquad ativa)
+    fn draw(&self, color: Color);
+
+    /// Retorna o centro do objeto
+    fn center(&self) -> Vec3<T>;
+
+    /// Checa se um ponto está dentro do objeto
+    fn contains_point(&self, point: Vec3<T>) -> bool;
+
+    /// Retorna o ponto da superfície do objeto mais próximo de um ponto
+    fn closest_point(&self, point: Vec3<T>) -> Vec3<T>;
+
+    /// Retorna os valores min,max da projeção do objeto sobre um eixo
+    fn project(&self, axis: Vec3<T>) -> (T, T);
+
+    /// Retorna as direções das arestas do objeto. Em 3D o SAT também precisa
+    /// testar os produtos vetoriais entre as arestas dos dois objetos.
+    /// (Vazio para esferas, como as `edges()` vazias do círculo em 2D.)
+    fn edge_directions(&self) -> Vec<Vec3<T>>;
+
+    /// Retorna os eixos (normais das faces) com que o objeto contribui para o cálculo.
+    /// (O "other" é necessário para definir o eixo da esfera, como no círculo.)
+    fn sat_axes(&self, other: &dyn Collider3<T>) -> Vec<Vec3<T>>;
+}
+
+/// Checa se um objeto colide com o outro usando SAT
+pub fn collides<T: Scalar>(a: &dyn Collider3<T>, b: &dyn Collider3<T>) -> bool {
+    // Normais das faces dos dois objetos
+    let mut axes = a.sat_axes(b);
+    axes.extend(b.sat_axes(a));
+    // Produtos vetoriais entre as arestas (contato aresta com aresta).
+    // Arestas paralelas dão um eixo nulo, que é descartado.
+    for ea in a.edge_directions() {
+        for eb in b.edge_directions() {
+            if !ea.is_parallel(eb) {
+                axes.push(ea.cross(eb).normalized());
+            }
+        }
+    }
+    // Filtra os eixos paralelos (repetidos)
+    let mut unique: Vec<Vec3<T>> = Vec::with_capacity(axes.len());
+    for axis in axes {
+        if !unique.iter().any(|u| u.is_parallel(axis)) {
+            unique.push(axis);
+        }
+    }
+
+    // (SAT) Se algum eixo indica separação entre os objetos,
+    // isso é suficiente para indicar que eles não estão colidindo.
+    !unique.into_iter().any(|axis| {
+        let (min_a, max_a) = a.project(axis);
+        let (min_b, max_b) = b.project(axis);
+        max_a < min_b || max_b < min_a
+    })
+}
+
+/// (private) Ponto da superfície de uma caixa (em coordenadas locais, centrada na
+/// origem) mais próximo de um ponto local. Pontos de dentro vão para a face mais próxima.
+pub(crate) fn closest_point_on_box<T: Scalar>(extents: Vec3<T>, point: Vec3<T>) -> Vec3<T> {
+    let clamped = Vec3::new(
+        point.x.clamp(-extents.x, extents.x),
+        point.y.clamp(-extents.y, extents.y),
+        point.z.clamp(-extents.z, extents.z),
+    );
+    if clamped != point {
+        return clamped;
+    }
+    // Dentro da caixa: empurra o ponto para a face com a menor folga
+    let gap = extents - point.abs();
+    let mut surface = point;
+    if gap.x <= gap.y && gap.x <= gap.z {
+        surface.x = extents.x * sign(point.x);
+    } else if gap.y <= gap.z {
+        surface.y = extents.y * sign(point.y);
+    } else {
+        surface.z = extents.z * sign(point.z);
+    }
+    surface
+}
+
+/// (private) Sinal de um número, com 0 contando como positivo
+fn sign<T: Scalar>(value: T) -> T {
+    if value < T::ZERO { -T::ONE } else { T::ONE }
+}
@@ -0,0 +1,9 @@
+mod aabb3;
+mod collider3;
+mod obb3;
+mod sphere;
+pub use aabb3::AABB3;
+pub use collider3::Collider3;
+pub use collider3::collides;
+pub use obb3::OBB3;
+pub use sphere::Sphere;
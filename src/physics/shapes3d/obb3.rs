@@ -0,0 +1,272 @@
+use rayon::prelude::*;
+
+use macroquad::{color::Color, models::draw_line_3d};
+
+use crate::{
+    algebra::{Mat3x3, Quat, Scalar, Vec3},
+    physics::shapes3d::{Collider3, collider3::closest_point_on_box},
+};
+
+/// Passo da busca grossa de orientações em `enclosing`, em graus
+const COARSE_STEP: i32 = 10;
+
+/// Número de refinamentos da busca de orientações (cada um divide o passo por 2)
+const REFINEMENTS: usize = 6;
+
+/// Caixa 3D orientada (equivalente 3D do `OBB`). `u`, `v` e `w` são os eixos
+/// locais (unitários e perpendiculares) e `extents` a metade do tamanho em cada um.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OBB3<T = f64> {
+    pub center: Vec3<T>,
+    pub extents: Vec3<T>,
+    pub u: Vec3<T>,
+    pub v: Vec3<T>,
+    pub w: Vec3<T>,
+}
+
+impl<T: Scalar> OBB3<T> {
+    #[inline]
+    #[must_use]
+    /// Construtor genérico de OBB3.
+    pub fn new(center: Vec3<T>, extents: Vec3<T>, u: Vec3<T>, v: Vec3<T>, w: Vec3<T>) -> OBB3<T> {
+        OBB3 {
+            center,
+            extents,
+            u: u.normalized(),
+            v: v.normalized(),
+            w: w.normalized(),
+        }
+    }
+
+    /// Cria uma OBB3 que engloba todos os pontos.
+    /// Em 3D a força bruta do `OBB::enclosing` vira uma busca sobre orientações:
+    /// primeiro uma grade de ângulos de Euler a cada 10° (em paralelo), depois
+    /// refinamentos locais ao redor da melhor caixa com passos cada vez menores.
+    /// Pânico se points.len() == 0
+    pub fn enclosing(points: &[Vec3<T>]) -> OBB3<T> {
+        assert!(!points.is_empty(), "Número de pontos deve ser maior que 0!");
+        // Por causa da simetria da caixa, yaw em [0°,180°), pitch em [-90°,90°)
+        // e roll em [0°,90°) já cobrem todas as orientações diferentes
+        let mut grid = Vec::new();
+        for yaw in (0..180).step_by(COARSE_STEP as usize) {
+            for pitch in (-90..90).step_by(COARSE_STEP as usize) {
+                for roll in (0..90).step_by(COARSE_STEP as usize) {
+                    grid.push([roll, pitch, yaw].map(|a| (a as f64).to_radians()));
+                }
+            }
+        }
+        let volume = |angles: &[f64; 3]| OBB3::from_euler_enclosing(points, *angles).volume();
+        // O volume é calculado uma vez por orientação, antes de comparar
+        let (_, mut best) = grid
+            .into_par_iter() // Faz as computações em paralelo usando a biblioteca Rayon
+            .map(|a| (volume(&a), a))
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .unwrap();
+
+        // Refinamento: testa os vizinhos da melhor orientação, diminuindo o passo
+        let mut step = (COARSE_STEP as f64).to_radians();
+        for _ in 0..REFINEMENTS {
+            step /= 2.0;
+            let offsets = [-step, 0.0, step];
+            let mut neighbours = Vec::with_capacity(27);
+            for dr in offsets {
+                for dp in offsets {
+                    for dy in offsets {
+                        neighbours.push([best[0] + dr, best[1] + dp, best[2] + dy]);
+                    }
+                }
+            }
+            (_, best) = neighbours
+                .into_iter()
+                .map(|a| (volume(&a), a))
+                .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                .unwrap();
+        }
+        OBB3::from_euler_enclosing(points, best)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna o volume da OBB3
+    pub fn volume(&self) -> T {
+        let size = self.extents * T::from_f64(2.0);
+        size.x * size.y * size.z
+    }
+
+    /// Cria a OBB3 com os eixos dados (unitários e perpendiculares) que engloba os pontos
+    pub fn from_axes_enclosing(points: &[Vec3<T>], u: Vec3<T>, v: Vec3<T>, w: Vec3<T>) -> OBB3<T> {
+        // Obtém os extents de acordo com a projeção dos pontos nos eixos
+        let range = |axis: Vec3<T>| {
+            points
+                .iter()
+                .map(|p| p.dot(axis))
+                .fold((T::INFINITY, -T::INFINITY), |(min, max), x| {
+                    (min.min(x), max.max(x))
+                })
+        };
+        let ((min_u, max_u), (min_v, max_v), (min_w, max_w)) = (range(u), range(v), range(w));
+        let two = T::from_f64(2.0);
+        let extents = Vec3::new(max_u - min_u, max_v - min_v, max_w - min_w) / two;
+
+        // Calcula o centro da OBB3
+        let center = (u * (min_u + max_u) + v * (min_v + max_v) + w * (min_w + max_w)) / two;
+        OBB3::new(center, extents, u, v, w)
+    }
+
+    /// (private) `from_axes_enclosing` com os eixos girados por ângulos de Euler
+    /// (roll ao redor do X, depois pitch ao redor do Y e yaw ao redor do Z)
+    fn from_euler_enclosing(points: &[Vec3<T>], [roll, pitch, yaw]: [f64; 3]) -> OBB3<T> {
+        let rotate = |axis: Vec3<T>| {
+            axis.rotated(Vec3::X, T::from_f64(roll))
+                .rotated(Vec3::Y, T::from_f64(pitch))
+                .rotated(Vec3::Z, T::from_f64(yaw))
+        };
+        OBB3::from_axes_enclosing(points, rotate(Vec3::X), rotate(Vec3::Y), rotate(Vec3::Z))
+    }
+
+    #[must_use]
+    /// Retorna as 8 pontas da OBB3
+    pub fn corners(&self) -> [Vec3<T>; 8] {
+        let (u, v, w) = (
+            self.u * self.extents.x,
+            self.v * self.extents.y,
+            self.w * self.extents.z,
+        );
+        // O bit 0 escolhe o lado em U, o bit 1 em V e o bit 2 em W
+        std::array::from_fn(|i| {
+            let side = |bit: usize| if i & (1 << bit) == 0 { -T::ONE } else { T::ONE };
+            self.center + u * side(0) + v * side(1) + w * side(2)
+        })
+    }
+
+    #[must_use]
+    /// (private) Converte um ponto do mundo para o espaço local da OBB3
+    fn local_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let p = point - self.center;
+        Vec3::new(p.dot(self.u), p.dot(self.v), p.dot(self.w))
+    }
+
+    /// Checa se a OBB3 contém um ponto
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        // Faz o check padrão como em uma AABB3, no espaço local
+        let local = self.local_point(point);
+        local.x.abs() < self.extents.x
+            && local.y.abs() < self.extents.y
+            && local.z.abs() < self.extents.z
+    }
+
+    /// Desenha as 12 arestas da OBB3 (precisa de uma câmera 3D da Macroquad ativa)
+    pub fn draw(&self, color: Color) {
+        let corners = self.corners();
+        // Arestas ligam pontas que diferem em um único bit
+        for (i, a) in corners.iter().enumerate() {
+            for bit in [1, 2, 4] {
+                if i & bit == 0 {
+                    draw_line_3d((*a).into(), corners[i | bit].into(), color);
+                }
+            }
+        }
+    }
+}
+
+// Métodos que dependem das rotações (só em f64)
+impl OBB3 {
+    #[inline]
+    #[must_use]
+    /// Cria uma OBB3 a partir do centro, da rotação e dos extents
+    pub fn from_rotation(center: Vec3, extents: Vec3, rotation: Quat) -> OBB3 {
+        OBB3::new(
+            center,
+            extents,
+            rotation.x_axis(),
+            rotation.y_axis(),
+            rotation.z_axis(),
+        )
+    }
+
+    /// Cria a OBB3 com os eixos girados por uma rotação que engloba os pontos
+    pub fn from_rotation_enclosing(points: &[Vec3], rotation: Quat) -> OBB3 {
+        OBB3::from_axes_enclosing(
+            points,
+            rotation.x_axis(),
+            rotation.y_axis(),
+            rotation.z_axis(),
+        )
+    }
+
+    #[must_use]
+    /// Retorna a rotação da OBB3 (a que leva os eixos X, Y e Z para U, V e W).
+    /// Se os eixos formarem um sistema de mão esquerda, o W é invertido.
+    pub fn rotation(&self) -> Quat {
+        let w = if self.u.cross(self.v).dot(self.w) < 0.0 {
+            -self.w
+        } else {
+            self.w
+        };
+        Quat::from_mat3x3(&Mat3x3::new(self.u, self.v, w))
+    }
+}
+
+impl<T: Scalar> Collider3<T> for OBB3<T> {
+    fn grow(&mut self, amount: Vec3<T>) {
+        self.extents = (self.extents + amount).max(Vec3::NULL);
+    }
+
+    fn rotate(&mut self, axis: Vec3<T>, theta: T) {
+        // Gira U e V e refaz W e V perpendiculares (mantendo o lado),
+        // assim os eixos não perdem a ortogonalidade depois de muitas rotações
+        let handedness = if self.u.cross(self.v).dot(self.w) < T::ZERO {
+            -T::ONE
+        } else {
+            T::ONE
+        };
+        self.u = self.u.rotated(axis, theta).normalized();
+        let v = self.v.rotated(axis, theta);
+        self.w = self.u.cross(v).normalized() * handedness;
+        self.v = (self.w.cross(self.u) * handedness).normalized();
+    }
+
+    fn set_center(&mut self, pos: Vec3<T>) {
+        self.center = pos;
+    }
+
+    fn size(&self) -> Vec3<T> {
+        self.extents * T::from_f64(2.0)
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.center
+    }
+
+    fn draw(&self, color: Color) {
+        self.draw(color);
+    }
+
+    fn contains_point(&self, point: Vec3<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let local = closest_point_on_box(self.extents, self.local_point(point));
+        self.center + self.u * local.x + self.v * local.y + self.w * local.z
+    }
+
+    fn project(&self, axis: Vec3<T>) -> (T, T) {
+        // Projeção do centro da caixa sobre o eixo
+        let center_p = self.center.dot(axis);
+        // Projeção da metade da caixa sobre o eixo
+        let extents_p = self.extents.x * axis.dot(self.u).abs()
+            + self.extents.y * axis.dot(self.v).abs()
+            + self.extents.z * axis.dot(self.w).abs();
+        // min, max
+        (center_p - extents_p, center_p + extents_p)
+    }
+
+    fn edge_directions(&self) -> Vec<Vec3<T>> {
+        vec![self.u, self.v, self.w]
+    }
+
+    fn sat_axes(&self, _other: &dyn Collider3<T>) -> Vec<Vec3<T>> {
+        vec![self.u, self.v, self.w]
+    }
+}
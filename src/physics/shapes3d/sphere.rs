@@ -0,0 +1,128 @@
+use macroquad::{color::Color, models::draw_sphere_wires};
+
+use crate::{
+    algebra::{Scalar, Vec3},
+    physics::shapes3d::{AABB3, Collider3},
+};
+
+/// Esfera (equivalente 3D do `Circle`)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sphere<T = f64> {
+    pub center: Vec3<T>,
+    pub radius: T,
+}
+
+impl<T: Scalar> Sphere<T> {
+    #[inline]
+    #[must_use]
+    /// Gera uma esfera com centro e raio definidos.
+    pub fn new(center: Vec3<T>, radius: T) -> Sphere<T> {
+        Sphere { center, radius }
+    }
+
+    #[must_use]
+    /// Gera uma bounding sphere que contém todos os pontos.
+    /// Faz isso gerando uma esfera no centro de uma AABB3 e usando a maior
+    /// distância do centro até um ponto como raio.
+    /// Pânico se points.len() == 0
+    pub fn enclosing(points: &[Vec3<T>]) -> Sphere<T> {
+        let aabb = AABB3::enclosing(points);
+        let center = (aabb.max + aabb.min) / T::from_f64(2.0);
+        let radius = points
+            .iter()
+            .map(|p| p.distance_to_squared(center))
+            .max_by(|a, b| a.total_cmp(b))
+            .unwrap()
+            .sqrt();
+        Sphere { center, radius }
+    }
+
+    #[inline]
+    /// Desenha o frame da esfera (precisa de uma câmera 3D da Macroquad ativa)
+    pub fn draw(&self, color: Color) {
+        draw_sphere_wires(self.center.into(), self.radius.to_f32(), None, color);
+    }
+
+    #[inline]
+    #[must_use]
+    /// Checa se se um ponto está dentro da esfera.
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        point.distance_to_squared(self.center) < self.radius * self.radius
+    }
+
+    #[inline]
+    /// (private) Retorna o eixo entre o centro da esfera e um ponto
+    /// (Vetor vazio se os pontos forem iguais. Evita vetor degenerado.)
+    fn sanitized_axis(&self, point: Vec3<T>) -> Vec<Vec3<T>> {
+        if self.center.is_same(point) {
+            vec![]
+        } else {
+            vec![(self.center - point).normalized()]
+        }
+    }
+}
+
+impl<T: Scalar> Collider3<T> for Sphere<T> {
+    fn grow(&mut self, amount: Vec3<T>) {
+        // Usa a componente de maior valor absoluto
+        let mut delta = amount.x;
+        for d in [amount.y, amount.z] {
+            if d.abs() > delta.abs() {
+                delta = d;
+            }
+        }
+        self.radius = (self.radius + delta).max(T::ZERO);
+    }
+
+    fn rotate(&mut self, _axis: Vec3<T>, _theta: T) {}
+
+    fn set_center(&mut self, pos: Vec3<T>) {
+        self.center = pos
+    }
+
+    fn size(&self) -> Vec3<T> {
+        let diameter = self.radius * T::from_f64(2.0);
+        Vec3::new(diameter, diameter, diameter)
+    }
+
+    fn sat_axes(&self, other: &dyn Collider3<T>) -> Vec<Vec3<T>> {
+        // Caso 1: Esfera -> Eixo entre os dois centros
+        if other.edge_directions().is_empty() {
+            self.sanitized_axis(other.center())
+        // Caso 2: Caixa -> Eixo entre o centro da esfera e o ponto mais próximo da caixa
+        } else {
+            self.sanitized_axis(other.closest_point(self.center))
+        }
+    }
+
+    fn edge_directions(&self) -> Vec<Vec3<T>> {
+        vec![]
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.center
+    }
+
+    fn draw(&self, color: Color) {
+        self.draw(color);
+    }
+
+    fn contains_point(&self, point: Vec3<T>) -> bool {
+        self.contains_point(point)
+    }
+
+    fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        // Ponto no centro: qualquer direção serve
+        let direction = if self.center.is_same(point) {
+            Vec3::X
+        } else {
+            (point - self.center).normalized()
+        };
+        self.center + direction * self.radius
+    }
+
+    fn project(&self, axis: Vec3<T>) -> (T, T) {
+        let c = self.center.dot(axis); // projeção do centro pro eixo
+        (c - self.radius, c + self.radius)
+    }
+}
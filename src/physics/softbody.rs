@@ -0,0 +1,192 @@
+use std::f64::consts::TAU;
+
+use macroquad::{color::Color, shapes::draw_line};
+
+use crate::{
+    algebra::Vec2,
+    physics::shapes::{Particle, Wall},
+};
+
+/// Ponto de massa de um corpo macio
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MassPoint {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    /// Força acumulada no passo atual
+    pub force: Vec2,
+}
+
+/// Mola amortecida entre dois pontos de massa
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Spring {
+    pub a: usize,
+    pub b: usize,
+    pub rest_length: f64,
+    /// Constante elástica (lei de Hooke)
+    pub stiffness: f64,
+    /// Coeficiente de amortecimento (aplicado à velocidade relativa ao longo da mola)
+    pub damping: f64,
+}
+
+/// Corpo macio baseado em pressão: um anel fechado de pontos de massa ligados por molas,
+/// "inflado" por uma força de pressão interna proporcional a `1 / área` (gás ideal).
+pub struct SoftBody {
+    /// Pontos do anel, em ordem
+    pub points: Vec<MassPoint>,
+    pub springs: Vec<Spring>,
+    /// Massa de cada ponto
+    pub point_mass: f64,
+    /// Constante `nRT` do gás interno. A pressão é `pressure / área`.
+    pub pressure: f64,
+    pub gravity: Vec2,
+    /// Número de sub-passos por atualização (molas rígidas precisam de passos menores)
+    pub substeps: usize,
+}
+
+impl SoftBody {
+    #[must_use]
+    /// Cria um corpo macio circular com `count` pontos
+    pub fn ring(
+        center: Vec2,
+        radius: f64,
+        count: usize,
+        stiffness: f64,
+        damping: f64,
+        pressure: f64,
+        gravity: Vec2,
+    ) -> SoftBody {
+        let count = count.max(3);
+        let points: Vec<MassPoint> = (0..count)
+            .map(|i| MassPoint {
+                position: center + Vec2::from_angle(TAU * i as f64 / count as f64) * radius,
+                velocity: Vec2::NULL,
+                force: Vec2::NULL,
+            })
+            .collect();
+
+        // Liga cada ponto ao próximo, fechando o anel
+        let springs = (0..count)
+            .map(|i| {
+                let j = (i + 1) % count;
+                Spring {
+                    a: i,
+                    b: j,
+                    rest_length: points[i].position.distance_to(points[j].position),
+                    stiffness,
+                    damping,
+                }
+            })
+            .collect();
+
+        SoftBody {
+            points,
+            springs,
+            point_mass: 1.0,
+            pressure,
+            gravity,
+            substeps: 4,
+        }
+    }
+
+    #[must_use]
+    /// Retorna a área com sinal do anel (fórmula do laço / shoelace)
+    pub fn signed_area(&self) -> f64 {
+        let n = self.points.len();
+        (0..n)
+            .map(|i| {
+                let (a, b) = (self.points[i].position, self.points[(i + 1) % n].position);
+                a.cross(b)
+            })
+            .sum::<f64>()
+            / 2.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a área do anel
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    #[must_use]
+    /// Retorna o centro de massa do corpo
+    pub fn center(&self) -> Vec2 {
+        self.points.iter().map(|p| p.position).sum::<Vec2>() / self.points.len() as f64
+    }
+
+    /// Avança a simulação em `delta` segundos. Os pontos colidem com as paredes
+    /// usando a mesma lógica de interseção de `Particle::update_with_walls`.
+    pub fn update(&mut self, delta: f64, walls: &[Wall]) {
+        let substeps = self.substeps.max(1);
+        let dt = delta / substeps as f64;
+        for _ in 0..substeps {
+            self.accumulate_forces();
+            self.integrate(dt, walls);
+        }
+    }
+
+    /// (private) Calcula gravidade, forças das molas e a força de pressão
+    fn accumulate_forces(&mut self) {
+        for point in &mut self.points {
+            point.force = self.gravity * self.point_mass;
+        }
+
+        // Molas: Hooke + amortecimento ao longo da direção da mola
+        for spring in &self.springs {
+            let (a, b) = (self.points[spring.a], self.points[spring.b]);
+            let delta = b.position - a.position;
+            let length = delta.length();
+            if length <= f64::EPSILON {
+                continue;
+            }
+            let direction = delta / length;
+            let stretch = length - spring.rest_length;
+            let relative_speed = (b.velocity - a.velocity).dot(direction);
+            let force = direction * (spring.stiffness * stretch + spring.damping * relative_speed);
+            self.points[spring.a].force += force;
+            self.points[spring.b].force -= force;
+        }
+
+        // Pressão: cada aresta é empurrada pra fora com força P * comprimento,
+        // dividida entre as suas duas pontas
+        let signed_area = self.signed_area();
+        if signed_area.abs() <= f64::EPSILON {
+            return;
+        }
+        let pressure = self.pressure / signed_area.abs();
+        // Orientação da normal "pra fora" depende do sentido do anel
+        let orientation = signed_area.signum();
+        let n = self.points.len();
+        for i in 0..n {
+            let j = (i + 1) % n;
+            let edge = self.points[j].position - self.points[i].position;
+            // (edge.y, -edge.x) tem o comprimento da aresta, então já vale P * L
+            let force = Vec2::new(edge.y, -edge.x) * orientation * pressure / 2.0;
+            self.points[i].force += force;
+            self.points[j].force += force;
+        }
+    }
+
+    /// (private) Integra os pontos (Euler semi-implícito) e colide com as paredes
+    fn integrate(&mut self, dt: f64, walls: &[Wall]) {
+        for point in &mut self.points {
+            point.velocity += point.force / self.point_mass * dt;
+
+            let mut particle = Particle::new(point.position, point.velocity);
+            particle.update_with_walls(dt, walls);
+            point.position = particle.position;
+            point.velocity = particle.speed;
+        }
+    }
+
+    /// Desenha o contorno do corpo
+    pub fn draw(&self, thickness: f32, color: Color) {
+        let n = self.points.len();
+        for i in 0..n {
+            let (a, b) = (self.points[i].position, self.points[(i + 1) % n].position);
+            draw_line(
+                a.x as f32, a.y as f32, b.x as f32, b.y as f32, thickness, color,
+            );
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use macroquad::{
+    color::{Color, RED},
+    shapes::draw_line,
+};
+
+use crate::{
+    algebra::Vec2,
+    physics::{body::RigidBody, shapes::OBB},
+};
+
+/// Número de vezes que os impulsos laterais das rodas são resolvidos por passo
+const LATERAL_ITERATIONS: usize = 4;
+
+/// Roda de um veículo visto de cima
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Wheel {
+    /// Posição da roda no espaço local do carro (x ao longo de `u`, y ao longo de `v`)
+    pub offset: Vec2,
+    /// Se a roda gira com o volante
+    pub steerable: bool,
+    /// Se o motor empurra esta roda
+    pub driven: bool,
+    /// Força lateral máxima que o pneu aguenta antes de escorregar
+    pub grip: f64,
+    skidding: bool,
+}
+
+impl Wheel {
+    #[inline]
+    #[must_use]
+    /// Construtor da roda
+    pub fn new(offset: Vec2, steerable: bool, driven: bool, grip: f64) -> Wheel {
+        Wheel {
+            offset,
+            steerable,
+            driven,
+            grip,
+            skidding: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna se a roda está derrapando (escorregou mais que o limite de drift no último passo)
+    pub fn is_skidding(&self) -> bool {
+        self.skidding
+    }
+}
+
+/// Veículo para jogos vistos de cima, com uma OBB como carroceria.
+/// O eixo `u` da OBB é a frente do carro. Cada roda recebe impulsos laterais que
+/// cancelam o escorregamento de lado (até o limite de aderência do pneu),
+/// além das forças do motor e do freio.
+pub struct Vehicle {
+    pub body: RigidBody<OBB>,
+    pub wheels: Vec<Wheel>,
+    /// Ângulo atual das rodas dianteiras em radianos (positivo gira de `u` para `v`)
+    pub steering: f64,
+    pub max_steering: f64,
+    /// Força total do motor (dividida entre as rodas com tração)
+    pub engine_force: f64,
+    /// Força total do freio (dividida entre todas as rodas)
+    pub brake_force: f64,
+    /// Velocidade de escorregamento lateral a partir da qual a roda derrapa
+    pub drift_threshold: f64,
+    /// Fração da aderência que sobra enquanto a roda derrapa
+    pub drift_grip: f64,
+    /// Resistência ao rolamento (desaceleração proporcional à velocidade para frente)
+    pub rolling_resistance: f64,
+}
+
+impl Vehicle {
+    #[must_use]
+    /// Construtor de um carro com 4 rodas (dianteiras com direção, traseiras com tração).
+    /// `extents` é a metade do comprimento (ao longo de `u`) e a metade da largura.
+    pub fn new(center: Vec2, extents: Vec2, angle: f64, mass: f64) -> Vehicle {
+        let body = RigidBody::new(OBB::from_angle(center, extents, angle), mass);
+        let grip = mass * 250.0;
+        let (front, side) = (extents.x * 0.7, extents.y);
+        let wheels = vec![
+            Wheel::new(Vec2::new(front, -side), true, false, grip),
+            Wheel::new(Vec2::new(front, side), true, false, grip),
+            Wheel::new(Vec2::new(-front, -side), false, true, grip),
+            Wheel::new(Vec2::new(-front, side), false, true, grip),
+        ];
+        Vehicle {
+            body,
+            wheels,
+            steering: 0.0,
+            max_steering: 0.6,
+            engine_force: mass * 400.0,
+            brake_force: mass * 800.0,
+            drift_threshold: 60.0,
+            drift_grip: 0.5,
+            rolling_resistance: 0.5,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a velocidade na direção da frente do carro (negativa se de ré)
+    pub fn forward_speed(&self) -> f64 {
+        self.body.velocity.dot(self.body.collider.u)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna se alguma roda está derrapando
+    pub fn is_drifting(&self) -> bool {
+        self.wheels.iter().any(Wheel::is_skidding)
+    }
+
+    #[must_use]
+    /// Retorna a posição de uma roda no mundo
+    pub fn wheel_position(&self, wheel: &Wheel) -> Vec2 {
+        let obb = &self.body.collider;
+        obb.center + obb.u * wheel.offset.x + obb.v * wheel.offset.y
+    }
+
+    #[must_use]
+    /// Retorna a direção para a qual uma roda aponta no mundo
+    pub fn wheel_forward(&self, wheel: &Wheel) -> Vec2 {
+        if wheel.steerable {
+            self.body.collider.u.rotated(self.steering)
+        } else {
+            self.body.collider.u
+        }
+    }
+
+    /// Avança a simulação do carro.
+    /// `throttle` em [-1,1] (negativo é ré), `brake` em [0,1] e `steer` em [-1,1].
+    pub fn update(&mut self, delta: f64, throttle: f64, brake: f64, steer: f64) {
+        self.steering = steer.clamp(-1.0, 1.0) * self.max_steering;
+        let driven = self
+            .wheels
+            .iter()
+            .filter(|wheel| wheel.driven)
+            .count()
+            .max(1) as f64;
+        let count = self.wheels.len().max(1) as f64;
+
+        // Motor, freio e resistência ao rolamento (calculados com as velocidades do início do passo)
+        let forces: Vec<(Vec2, Vec2)> = self
+            .wheels
+            .iter()
+            .map(|wheel| {
+                let (point, forward) = (self.wheel_position(wheel), self.wheel_forward(wheel));
+                let mut impulse = 0.0;
+                if wheel.driven {
+                    impulse += throttle.clamp(-1.0, 1.0) * self.engine_force / driven * delta;
+                }
+                // O freio nunca inverte o sentido da roda
+                let rolling = self.body.velocity_at(point).dot(forward);
+                let resistance = brake.clamp(0.0, 1.0) * self.brake_force / count * delta
+                    + rolling.abs() * self.rolling_resistance * self.body.mass / count * delta;
+                let stop = rolling.abs() * self.effective_mass(point, forward) / count;
+                impulse -= rolling.signum() * resistance.min(stop);
+                (forward * impulse, point)
+            })
+            .collect();
+        for (impulse, point) in forces {
+            self.body.apply_impulse(impulse, point);
+        }
+
+        // Impulsos laterais que zeram o escorregamento de lado das rodas.
+        // Resolvidos algumas vezes em sequência (as rodas se influenciam pela rotação),
+        // com o impulso acumulado de cada roda limitado pela aderência.
+        let mut accumulated = vec![0.0; self.wheels.len()];
+        for iteration in 0..LATERAL_ITERATIONS {
+            for (i, accumulated) in accumulated.iter_mut().enumerate() {
+                let wheel = self.wheels[i];
+                let point = self.wheel_position(&wheel);
+                let forward = self.wheel_forward(&wheel);
+                let lateral = Vec2::new(-forward.y, forward.x);
+
+                let slip = self.body.velocity_at(point).dot(lateral);
+                if iteration == 0 {
+                    self.wheels[i].skidding = slip.abs() > self.drift_threshold;
+                }
+                let grip = if self.wheels[i].skidding {
+                    wheel.grip * self.drift_grip
+                } else {
+                    wheel.grip
+                };
+                let max_impulse = grip * delta;
+                let total = (*accumulated - slip * self.effective_mass(point, lateral))
+                    .clamp(-max_impulse, max_impulse);
+                self.body
+                    .apply_impulse(lateral * (total - *accumulated), point);
+                *accumulated = total;
+            }
+        }
+
+        self.body.integrate(delta, Vec2::NULL);
+    }
+
+    /// Desenha a carroceria e as rodas (vermelhas quando derrapam)
+    pub fn draw(&self, thickness: f32, color: Color) {
+        self.body.draw(thickness, color);
+        let length = self.body.collider.extents.x * 0.3;
+        for wheel in &self.wheels {
+            let (point, forward) = (self.wheel_position(wheel), self.wheel_forward(wheel));
+            let (a, b) = (point - forward * length, point + forward * length);
+            let color = if wheel.skidding { RED } else { color };
+            draw_line(
+                a.x as f32,
+                a.y as f32,
+                b.x as f32,
+                b.y as f32,
+                thickness * 2.0,
+                color,
+            );
+        }
+    }
+
+    #[inline]
+    /// (private) Massa efetiva do corpo para um impulso em um ponto e direção:
+    /// `1 / (1/m + (r × n)² / I)`
+    fn effective_mass(&self, point: Vec2, direction: Vec2) -> f64 {
+        let r = point - self.body.position();
+        let rn = r.cross(direction);
+        1.0 / (1.0 / self.body.mass + rn * rn / self.body.inertia)
+    }
+}
@@ -0,0 +1,255 @@
+use macroquad::{color::Color, shapes::draw_line};
+
+use crate::{
+    algebra::Vec2,
+    physics::shapes::{Collider, Line, SURFACE_EPS, closest_hit},
+};
+
+/// Ponto de massa integrado com Verlet (a velocidade é implícita em `position - previous`)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PointMass {
+    pub position: Vec2,
+    pub previous: Vec2,
+    /// Pontos fixos não se movem (nem pela gravidade, nem pelas restrições)
+    pub pinned: bool,
+}
+
+impl PointMass {
+    #[inline]
+    #[must_use]
+    /// Construtor do ponto (começa parado)
+    pub fn new(position: Vec2, pinned: bool) -> PointMass {
+        PointMass {
+            position,
+            previous: position,
+            pinned,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retorna a velocidade implícita do ponto (deslocamento do último passo)
+    pub fn velocity(&self) -> Vec2 {
+        self.position - self.previous
+    }
+}
+
+/// Restrição de distância entre dois pontos
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DistanceConstraint {
+    pub a: usize,
+    pub b: usize,
+    pub rest_length: f64,
+    /// Fração da correção aplicada por iteração (1 = rígida)
+    pub stiffness: f64,
+    /// Deformação relativa `(comprimento - repouso) / repouso` a partir da qual
+    /// a restrição rasga (`f64::INFINITY` = nunca rasga)
+    pub tear_strain: f64,
+}
+
+impl DistanceConstraint {
+    #[inline]
+    #[must_use]
+    /// Retorna a deformação relativa atual da restrição
+    pub fn strain(&self, points: &[PointMass]) -> f64 {
+        let length = points[self.a].position.distance_to(points[self.b].position);
+        (length - self.rest_length) / self.rest_length
+    }
+}
+
+/// Sistema de pontos de massa com restrições de distância (cordas, tecidos, pontes)
+/// simulado com integração de Verlet e relaxação de restrições.
+pub struct VerletSystem {
+    pub points: Vec<PointMass>,
+    pub constraints: Vec<DistanceConstraint>,
+    pub gravity: Vec2,
+    /// Fração da velocidade mantida a cada passo (1 = sem amortecimento)
+    pub damping: f64,
+    /// Número de iterações de relaxação das restrições por passo
+    pub iterations: usize,
+}
+
+impl VerletSystem {
+    #[must_use]
+    /// Cria um sistema vazio
+    pub fn new(gravity: Vec2) -> VerletSystem {
+        VerletSystem {
+            points: Vec::new(),
+            constraints: Vec::new(),
+            gravity,
+            damping: 0.99,
+            iterations: 8,
+        }
+    }
+
+    #[must_use]
+    /// Cria uma corda com `segments` segmentos entre `start` e `end`.
+    /// O primeiro ponto fica fixo se `pin_start` for verdadeiro.
+    pub fn rope(
+        start: Vec2,
+        end: Vec2,
+        segments: usize,
+        pin_start: bool,
+        gravity: Vec2,
+    ) -> VerletSystem {
+        let mut system = VerletSystem::new(gravity);
+        let segments = segments.max(1);
+        for i in 0..=segments {
+            let t = i as f64 / segments as f64;
+            let point = system.add_point(start + (end - start) * t, pin_start && i == 0);
+            if i > 0 {
+                system.connect(point - 1, point, f64::INFINITY);
+            }
+        }
+        system
+    }
+
+    #[must_use]
+    /// Cria um tecido retangular de `columns` x `rows` pontos com canto superior esquerdo
+    /// em `top_left`, pendurado pelos pontos da linha de cima a cada `pin_every` colunas.
+    pub fn cloth(
+        top_left: Vec2,
+        size: Vec2,
+        columns: usize,
+        rows: usize,
+        pin_every: usize,
+        tear_strain: f64,
+        gravity: Vec2,
+    ) -> VerletSystem {
+        let mut system = VerletSystem::new(gravity);
+        let (columns, rows) = (columns.max(2), rows.max(2));
+        let spacing = Vec2::new(size.x / (columns - 1) as f64, size.y / (rows - 1) as f64);
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let position =
+                    top_left + Vec2::new(spacing.x * column as f64, spacing.y * row as f64);
+                let pinned = row == 0 && pin_every > 0 && column % pin_every == 0;
+                let point = system.add_point(position, pinned);
+                if column > 0 {
+                    system.connect(point - 1, point, tear_strain);
+                }
+                if row > 0 {
+                    system.connect(point - columns, point, tear_strain);
+                }
+            }
+        }
+        system
+    }
+
+    /// Adiciona um ponto ao sistema e retorna o seu índice
+    pub fn add_point(&mut self, position: Vec2, pinned: bool) -> usize {
+        self.points.push(PointMass::new(position, pinned));
+        self.points.len() - 1
+    }
+
+    /// Conecta dois pontos com uma restrição de distância rígida cujo comprimento
+    /// de repouso é a distância atual entre eles
+    pub fn connect(&mut self, a: usize, b: usize, tear_strain: f64) {
+        let rest_length = self.points[a].position.distance_to(self.points[b].position);
+        self.constraints.push(DistanceConstraint {
+            a,
+            b,
+            rest_length,
+            stiffness: 1.0,
+            tear_strain,
+        });
+    }
+
+    /// Fixa/solta um ponto
+    pub fn set_pinned(&mut self, index: usize, pinned: bool) {
+        self.points[index].pinned = pinned;
+    }
+
+    /// Avança a simulação em `delta` segundos: integra os pontos, relaxa as restrições,
+    /// resolve colisões com paredes e colliders e rasga as restrições muito esticadas.
+    pub fn update(&mut self, delta: f64, walls: &[Line], colliders: &[&dyn Collider]) {
+        // Posições no início do passo (usadas no teste de travessia das paredes)
+        let start: Vec<Vec2> = self.points.iter().map(|p| p.position).collect();
+
+        // Integração de Verlet
+        let acceleration = self.gravity * delta * delta;
+        for point in self.points.iter_mut().filter(|p| !p.pinned) {
+            let velocity = point.velocity() * self.damping;
+            point.previous = point.position;
+            point.position += velocity + acceleration;
+        }
+
+        for _ in 0..self.iterations {
+            self.solve_constraints();
+            self.solve_collisions(&start, walls, colliders);
+        }
+
+        // Rasga as restrições que passaram do limite de deformação
+        let points = &self.points;
+        self.constraints
+            .retain(|constraint| constraint.strain(points) <= constraint.tear_strain);
+    }
+
+    /// (private) Uma iteração de relaxação de todas as restrições de distância
+    fn solve_constraints(&mut self) {
+        for constraint in &self.constraints {
+            let (a, b) = (self.points[constraint.a], self.points[constraint.b]);
+            let delta = b.position - a.position;
+            let length = delta.length();
+            if length <= f64::EPSILON {
+                continue;
+            }
+
+            // Distribui a correção entre os pontos livres
+            let weight_a = if a.pinned { 0.0 } else { 1.0 };
+            let weight_b = if b.pinned { 0.0 } else { 1.0 };
+            let total_weight = weight_a + weight_b;
+            if total_weight == 0.0 {
+                continue;
+            }
+
+            let error = (length - constraint.rest_length) / length;
+            let correction = delta * error * constraint.stiffness / total_weight;
+            self.points[constraint.a].position += correction * weight_a;
+            self.points[constraint.b].position -= correction * weight_b;
+        }
+    }
+
+    /// (private) Empurra os pontos para fora das paredes e dos colliders
+    fn solve_collisions(&mut self, start: &[Vec2], walls: &[Line], colliders: &[&dyn Collider]) {
+        for (point, &from) in self.points.iter_mut().zip(start).filter(|(p, _)| !p.pinned) {
+            // Paredes: se o movimento do passo atravessou uma parede, volta pro ponto de contato
+            let movement_line = Line::new(from, point.position);
+            if let Some((_, intersection)) = closest_hit(movement_line, walls.iter().copied()) {
+                let normal = intersection.normal;
+                let away = if (point.position - from).dot(normal) > 0.0 {
+                    -normal
+                } else {
+                    normal
+                };
+                point.position = intersection.p + away * SURFACE_EPS;
+            }
+
+            // Colliders: pontos dentro do objeto vão para o ponto mais próximo da borda
+            for collider in colliders {
+                if collider.contains_point(point.position) {
+                    let surface = collider.closest_point(point.position);
+                    let push = surface - point.position;
+                    let away = if push.length_squared() > 0.0 {
+                        push.normalized()
+                    } else {
+                        Vec2::NULL
+                    };
+                    point.position = surface + away * SURFACE_EPS;
+                }
+            }
+        }
+    }
+
+    /// Desenha as restrições como linhas
+    pub fn draw(&self, thickness: f32, color: Color) {
+        for constraint in &self.constraints {
+            let a = self.points[constraint.a].position;
+            let b = self.points[constraint.b].position;
+            draw_line(
+                a.x as f32, a.y as f32, b.x as f32, b.y as f32, thickness, color,
+            );
+        }
+    }
+}
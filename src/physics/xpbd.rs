@@ -0,0 +1,413 @@
+use macroquad::{color::Color, shapes::draw_line};
+
+use crate::{
+    algebra::Vec2,
+    physics::shapes::{Collider, Line, SURFACE_EPS, closest_hit},
+};
+
+/// Partícula do solver XPBD
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct XpbdParticle {
+    pub position: Vec2,
+    /// Posição no início do passo (a velocidade é derivada dela no fim do passo)
+    pub previous: Vec2,
+    pub velocity: Vec2,
+    /// Inverso da massa (0 = partícula fixa / massa infinita)
+    pub inverse_mass: f64,
+}
+
+impl XpbdParticle {
+    #[inline]
+    #[must_use]
+    /// Construtor da partícula. `mass = f64::INFINITY` cria uma partícula fixa.
+    pub fn new(position: Vec2, mass: f64) -> XpbdParticle {
+        XpbdParticle {
+            position,
+            previous: position,
+            velocity: Vec2::NULL,
+            inverse_mass: 1.0 / mass,
+        }
+    }
+}
+
+/// Tipo de uma restrição do solver
+#[derive(Clone, PartialEq, Debug)]
+pub enum ConstraintKind {
+    /// Mantém a distância entre duas partículas
+    Distance {
+        a: usize,
+        b: usize,
+        rest_length: f64,
+    },
+    /// Mantém a distância entre as pontas `a` e `c` de uma dobra `a-b-c`
+    /// (resistência a dobrar de cordas e tecidos)
+    Bending {
+        a: usize,
+        b: usize,
+        c: usize,
+        rest_length: f64,
+    },
+    /// Mantém a área de um polígono fechado (corpos macios e volumes)
+    Area { points: Vec<usize>, rest_area: f64 },
+    /// Impede que uma partícula passe do lado negativo de um plano
+    /// (`normal.dot(p) >= offset`)
+    Collision {
+        particle: usize,
+        normal: Vec2,
+        offset: f64,
+    },
+}
+
+/// Restrição com complacência (inverso da rigidez).
+/// Complacência 0 = restrição rígida, independente do passo de tempo e das iterações.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Constraint {
+    pub kind: ConstraintKind,
+    pub compliance: f64,
+    /// Multiplicador de Lagrange acumulado no passo atual
+    lambda: f64,
+}
+
+impl Constraint {
+    #[inline]
+    #[must_use]
+    /// Construtor da restrição
+    pub fn new(kind: ConstraintKind, compliance: f64) -> Constraint {
+        Constraint {
+            kind,
+            compliance,
+            lambda: 0.0,
+        }
+    }
+}
+
+/// Solver de dinâmica baseada em posições estendida (XPBD, Macklin et al. 2016).
+/// Um único solver para cordas, tecidos, corpos macios e partículas rígidas:
+/// tudo é expresso como restrições sobre as posições das partículas.
+pub struct XpbdSolver {
+    pub particles: Vec<XpbdParticle>,
+    pub constraints: Vec<Constraint>,
+    pub gravity: Vec2,
+    /// Sub-passos por atualização (com uma iteração de restrições cada)
+    pub substeps: usize,
+}
+
+impl XpbdSolver {
+    #[must_use]
+    /// Cria um solver vazio
+    pub fn new(gravity: Vec2) -> XpbdSolver {
+        XpbdSolver {
+            particles: Vec::new(),
+            constraints: Vec::new(),
+            gravity,
+            substeps: 10,
+        }
+    }
+
+    /// Adiciona uma partícula e retorna o seu índice
+    pub fn add_particle(&mut self, position: Vec2, mass: f64) -> usize {
+        self.particles.push(XpbdParticle::new(position, mass));
+        self.particles.len() - 1
+    }
+
+    /// Adiciona uma restrição de distância com comprimento de repouso igual à distância atual
+    pub fn add_distance(&mut self, a: usize, b: usize, compliance: f64) {
+        let rest_length = self.particles[a]
+            .position
+            .distance_to(self.particles[b].position);
+        self.add(ConstraintKind::Distance { a, b, rest_length }, compliance);
+    }
+
+    /// Adiciona uma restrição de dobra entre as pontas `a` e `c` de `a-b-c`
+    pub fn add_bending(&mut self, a: usize, b: usize, c: usize, compliance: f64) {
+        let rest_length = self.particles[a]
+            .position
+            .distance_to(self.particles[c].position);
+        self.add(
+            ConstraintKind::Bending {
+                a,
+                b,
+                c,
+                rest_length,
+            },
+            compliance,
+        );
+    }
+
+    /// Adiciona uma restrição de área para um polígono fechado com a área atual como repouso
+    pub fn add_area(&mut self, points: Vec<usize>, compliance: f64) {
+        let rest_area = polygon_area(&self.particles, &points);
+        self.add(ConstraintKind::Area { points, rest_area }, compliance);
+    }
+
+    /// Adiciona uma restrição qualquer
+    pub fn add(&mut self, kind: ConstraintKind, compliance: f64) {
+        self.constraints.push(Constraint::new(kind, compliance));
+    }
+
+    /// Avança a simulação em `delta` segundos. Colisões com paredes e colliders são
+    /// geradas a cada sub-passo como restrições de colisão temporárias (rígidas).
+    pub fn update(&mut self, delta: f64, walls: &[Line], colliders: &[&dyn Collider]) {
+        let substeps = self.substeps.max(1);
+        let dt = delta / substeps as f64;
+
+        for _ in 0..substeps {
+            // Predição das posições
+            for particle in self.particles.iter_mut().filter(|p| p.inverse_mass > 0.0) {
+                particle.velocity += self.gravity * dt;
+                particle.previous = particle.position;
+                particle.position += particle.velocity * dt;
+            }
+
+            for constraint in &mut self.constraints {
+                constraint.lambda = 0.0;
+            }
+            for i in 0..self.constraints.len() {
+                self.solve(i, dt);
+            }
+
+            // Colisões por último: detectadas depois das outras restrições (que também
+            // movem as partículas), então nenhuma partícula termina o sub-passo atravessada
+            let permanent = self.constraints.len();
+            let contacts = self.collision_constraints(walls, colliders);
+            self.constraints.extend(contacts);
+            for i in permanent..self.constraints.len() {
+                self.solve(i, dt);
+            }
+            self.constraints.truncate(permanent);
+
+            // Velocidades derivadas das posições
+            for particle in self.particles.iter_mut().filter(|p| p.inverse_mass > 0.0) {
+                particle.velocity = (particle.position - particle.previous) / dt;
+            }
+        }
+    }
+
+    /// (private) Gera restrições de colisão para as partículas que atravessaram
+    /// uma parede ou estão dentro de um collider
+    fn collision_constraints(
+        &self,
+        walls: &[Line],
+        colliders: &[&dyn Collider],
+    ) -> Vec<Constraint> {
+        let mut contacts = Vec::new();
+        for (i, particle) in self.particles.iter().enumerate() {
+            if particle.inverse_mass == 0.0 {
+                continue;
+            }
+
+            contacts.extend(wall_contact(i, particle, walls));
+
+            for collider in colliders {
+                if collider.contains_point(particle.position) {
+                    let surface = collider.closest_point(particle.position);
+                    let push = surface - particle.position;
+                    if push.length_squared() > 0.0 {
+                        let normal = push.normalized();
+                        contacts.push(Constraint::new(
+                            ConstraintKind::Collision {
+                                particle: i,
+                                normal,
+                                offset: normal.dot(surface),
+                            },
+                            0.0,
+                        ));
+                    }
+                }
+            }
+        }
+        contacts
+    }
+
+    /// (private) Resolve uma restrição: calcula C, os gradientes e aplica
+    /// `Δλ = (-C - α̃λ) / (Σ wᵢ|∇Cᵢ|² + α̃)`, com `α̃ = compliance / dt²`
+    fn solve(&mut self, index: usize, dt: f64) {
+        let alpha = self.constraints[index].compliance / (dt * dt);
+        let (c, gradients) = match &self.constraints[index].kind {
+            ConstraintKind::Distance { a, b, rest_length } => {
+                distance_gradients(&self.particles, *a, *b, *rest_length)
+            }
+            ConstraintKind::Bending {
+                a, c, rest_length, ..
+            } => distance_gradients(&self.particles, *a, *c, *rest_length),
+            ConstraintKind::Area { points, rest_area } => {
+                area_gradients(&self.particles, points, *rest_area)
+            }
+            ConstraintKind::Collision {
+                particle,
+                normal,
+                offset,
+            } => {
+                let c = normal.dot(self.particles[*particle].position) - offset;
+                // Desigualdade: só corrige se a partícula estiver do lado errado
+                if c >= 0.0 {
+                    return;
+                }
+                (c, vec![(*particle, *normal)])
+            }
+        };
+
+        let denominator: f64 = gradients
+            .iter()
+            .map(|(i, g)| self.particles[*i].inverse_mass * g.length_squared())
+            .sum::<f64>()
+            + alpha;
+        if denominator <= f64::EPSILON {
+            return;
+        }
+
+        let lambda = self.constraints[index].lambda;
+        let delta_lambda = (-c - alpha * lambda) / denominator;
+        self.constraints[index].lambda += delta_lambda;
+        for (i, gradient) in gradients {
+            let inverse_mass = self.particles[i].inverse_mass;
+            self.particles[i].position += gradient * (delta_lambda * inverse_mass);
+        }
+    }
+
+    /// Desenha as restrições de distância como linhas
+    pub fn draw(&self, thickness: f32, color: Color) {
+        for constraint in &self.constraints {
+            if let ConstraintKind::Distance { a, b, .. } = constraint.kind {
+                let (a, b) = (self.particles[a].position, self.particles[b].position);
+                draw_line(
+                    a.x as f32, a.y as f32, b.x as f32, b.y as f32, thickness, color,
+                );
+            }
+        }
+    }
+}
+
+/// (private) Restrição de colisão para uma partícula cujo movimento no sub-passo
+/// atravessou uma parede (`None` se não atravessou)
+fn wall_contact(index: usize, particle: &XpbdParticle, walls: &[Line]) -> Option<Constraint> {
+    let movement = particle.position - particle.previous;
+    let movement_line = Line::new(particle.previous, particle.position);
+    let (_, intersection) = closest_hit(movement_line, walls.iter().copied())?;
+
+    // Normal virada pro lado em que a partícula estava no início do sub-passo
+    // (se ela começou exatamente sobre a parede, o lado oposto ao movimento)
+    let side = intersection.normal.dot(particle.previous - intersection.p);
+    let came_from_back = side < 0.0 || (side == 0.0 && movement.dot(intersection.normal) > 0.0);
+    let normal = if came_from_back {
+        -intersection.normal
+    } else {
+        intersection.normal
+    };
+    // Interseção em t ≈ 0 com a partícula se afastando da parede: não há contato
+    if movement.dot(normal) >= 0.0 {
+        return None;
+    }
+
+    // O alvo fica a `SURFACE_EPS` da parede, do lado de onde a partícula veio,
+    // para que o arredondamento não a deixe do outro lado no próximo sub-passo
+    Some(Constraint::new(
+        ConstraintKind::Collision {
+            particle: index,
+            normal,
+            offset: normal.dot(intersection.p) + SURFACE_EPS,
+        },
+        0.0,
+    ))
+}
+
+/// (private) C e gradientes de uma restrição de distância `|a - b| - rest_length`
+fn distance_gradients(
+    particles: &[XpbdParticle],
+    a: usize,
+    b: usize,
+    rest_length: f64,
+) -> (f64, Vec<(usize, Vec2)>) {
+    let delta = particles[a].position - particles[b].position;
+    let length = delta.length();
+    if length <= f64::EPSILON {
+        return (0.0, vec![]);
+    }
+    let n = delta / length;
+    (length - rest_length, vec![(a, n), (b, -n)])
+}
+
+/// (private) Área com sinal de um polígono de partículas (fórmula do laço)
+fn polygon_area(particles: &[XpbdParticle], points: &[usize]) -> f64 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let a = particles[points[i]].position;
+            let b = particles[points[(i + 1) % n]].position;
+            a.cross(b)
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// (private) C e gradientes de uma restrição de área `área - rest_area`.
+/// O gradiente da área em relação ao ponto i é `perp(p[i+1] - p[i-1]) / 2`.
+fn area_gradients(
+    particles: &[XpbdParticle],
+    points: &[usize],
+    rest_area: f64,
+) -> (f64, Vec<(usize, Vec2)>) {
+    let n = points.len();
+    let gradients = (0..n)
+        .map(|i| {
+            let next = particles[points[(i + 1) % n]].position;
+            let prev = particles[points[(i + n - 1) % n]].position;
+            let d = next - prev;
+            (points[i], Vec2::new(d.y, -d.x) / 2.0)
+        })
+        .collect();
+    (polygon_area(particles, points) - rest_area, gradients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRAVITY: Vec2 = Vec2 { x: 0.0, y: 980.0 };
+    const DELTA: f64 = 1.0 / 60.0;
+
+    #[test]
+    fn particles_do_not_fall_through_slanted_wall() {
+        let wall = Line::new(Vec2::new(-1000.0, 100.0), Vec2::new(1000.0, 130.0));
+        let mut solver = XpbdSolver::new(GRAVITY);
+        for i in 0..1000 {
+            let x = -900.0 + 1.8 * i as f64;
+            solver.add_particle(Vec2::new(x, 0.0), 1.0);
+        }
+
+        for _ in 0..120 {
+            solver.update(DELTA, &[wall], &[]);
+        }
+
+        for particle in &solver.particles {
+            let p = particle.position;
+            let wall_y = 100.0 + 30.0 * (p.x + 1000.0) / 2000.0;
+            assert!(p.y < wall_y, "partícula atravessou a parede: {p:?}");
+        }
+    }
+
+    #[test]
+    fn resting_particle_can_leave_the_floor() {
+        let floor = Line::new(Vec2::new(-1000.0, 100.0), Vec2::new(1000.0, 100.0));
+        let mut solver = XpbdSolver::new(GRAVITY);
+        let i = solver.add_particle(Vec2::new(0.0, 50.0), 1.0);
+
+        // Cai e fica parada sobre o chão
+        for _ in 0..120 {
+            solver.update(DELTA, &[floor], &[]);
+        }
+        let resting = solver.particles[i];
+        assert!(resting.position.y < 100.0 && resting.position.y > 100.0 - 1e-3);
+        assert!(resting.velocity.y.abs() < 1e-3);
+
+        // Lançada para cima, sai do chão
+        solver.particles[i].velocity.y = -500.0;
+        solver.update(DELTA, &[floor], &[]);
+        let launched = solver.particles[i];
+        assert!(
+            launched.position.y < 95.0,
+            "partícula presa no chão: {launched:?}"
+        );
+        assert!(launched.velocity.y < -400.0);
+    }
+}